@@ -1,4 +1,4 @@
-use math_ops::{IntoVector, Normalize, SortOps, Statistics, SummaryOps, Vector, VectorOps};
+use math_ops::{Impute, Interpolation, IntoVector, Normalize, SortOps, Statistics, SummaryOps, Vector, VectorMath, VectorOps};
 
 fn main() {
   // Sample data with NaN values
@@ -29,6 +29,12 @@ fn main() {
   let standardized = data_f64.standardize();
   println!("Standardized: {:?}", standardized);
 
+  // Imputation
+  println!("\n=== Imputation ===");
+  println!("Filled with Mean: {:?}", data_f64.fill_mean());
+  println!("Forward Filled: {:?}", data_f64.ffill(None));
+  println!("Linear Interpolation: {:?}", data_f64.interpolate(Interpolation::Linear));
+
   // Sorting
   println!("\n=== Sorting ===");
   let sorted = data_f64.sorted();
@@ -61,6 +67,18 @@ fn main() {
   println!("Divided by Scalar: {:?}", divided);
   let modulus = data_f64.rem_scalar(scalar);
   println!("Modulus with Scalar: {:?}", modulus);
+  let scaled = &data_f64 * scalar;
+  println!("Scaled with Operator: {:?}", scaled);
+  let mut accumulated = data2.clone();
+  accumulated += &data_f64;
+  accumulated *= scalar;
+  println!("Compound Assignment: {:?}", accumulated);
+
+  // Element-wise Math
+  println!("\n=== Element-wise Math ===");
+  println!("Square Root: {:?}", data_f64.sqrt());
+  println!("Natural Log: {:?}", data_f64.ln());
+  println!("Clamped to [2, 4]: {:?}", data_f64.clamp(2.0, 4.0));
 
   // Type Conversion
   println!("\n=== Type Conversion ===");
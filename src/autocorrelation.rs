@@ -0,0 +1,154 @@
+//! Autocorrelation, partial autocorrelation, cross-correlation and the Ljung-Box test
+//! for `Vector<T>` treated as an ordered series.
+//!
+//! Estimates are normalized by the full-series variance from `Statistics::var`, the
+//! usual biased estimator that keeps the autocorrelation sequence positive semi-definite.
+//! NaN values are ignored: products involving a NaN are left out of each lag's sum.
+
+use crate::error::{check_same_len, MathError};
+use crate::special::gamma_inc_upper;
+use crate::statistics::Statistics;
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::{Float, ToPrimitive};
+
+/// Result of a Ljung-Box test for autocorrelation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LjungBox<T> {
+  /// The Q statistic.
+  pub statistic: T,
+  /// p-value from the chi-squared distribution with `lags` degrees of freedom.
+  pub p_value: T,
+  /// Number of lags included in the statistic.
+  pub lags: usize,
+}
+
+/// Trait providing correlation diagnostics for time series.
+pub trait Autocorrelation<T> {
+  /// Computes the autocorrelation at lags `0..=max_lag`, so the first entry is 1.
+  /// Lags of at least the series length, and every lag of a series whose variance is
+  /// undefined or zero, are NaN.
+  fn autocorrelation(&self, max_lag: usize) -> Vector<T>;
+
+  /// Computes the partial autocorrelation at lags `0..=max_lag` from the
+  /// autocorrelations, using the Durbin-Levinson recursion.
+  fn pacf(&self, max_lag: usize) -> Vector<T>;
+
+  /// Computes the cross-correlation with `other` at lags `-max_lag..=max_lag`, returned
+  /// in that order. The entry at lag `k` correlates `self[t]` with `other[t + k]`.
+  /// Returns an error if the lengths differ.
+  fn cross_correlation(&self, other: &Vector<T>, max_lag: usize) -> Result<Vector<T>, MathError>;
+
+  /// Runs the Ljung-Box test that the autocorrelations at lags `1..=lags` are all zero.
+  /// Returns an error if `lags` is zero or not less than the number of non-NaN values.
+  fn ljung_box(&self, lags: usize) -> Result<LjungBox<T>, MathError>;
+}
+
+impl<T> Autocorrelation<T> for Vector<T>
+where
+  T: Float + ToPrimitive + Copy + PartialOrd,
+{
+  fn autocorrelation(&self, max_lag: usize) -> Vector<T> {
+    lagged_correlation(self, self).map_or_else(
+      || vec![T::nan(); max_lag + 1].into_vector(),
+      |corr| {
+        (0..=max_lag)
+          .map(|k| corr(k as isize))
+          .collect::<Vec<T>>()
+          .into_vector()
+      },
+    )
+  }
+
+  fn pacf(&self, max_lag: usize) -> Vector<T> {
+    let acf = self.autocorrelation(max_lag);
+    let mut pacf = vec![T::nan(); max_lag + 1];
+    pacf[0] = acf[0];
+    // phi holds the coefficients phi_{k, 1..=k} of the order-k autoregression.
+    let mut phi: Vec<T> = Vec::with_capacity(max_lag);
+    for k in 1..=max_lag {
+      let mut num = acf[k];
+      let mut den = T::one();
+      for (j, &p) in phi.iter().enumerate() {
+        num = num - p * acf[k - 1 - j];
+        den = den - p * acf[j + 1];
+      }
+      let phi_kk = num / den;
+      let previous = phi.clone();
+      for (j, p) in phi.iter_mut().enumerate() {
+        *p = *p - phi_kk * previous[k - 2 - j];
+      }
+      phi.push(phi_kk);
+      pacf[k] = phi_kk;
+    }
+    pacf.into_vector()
+  }
+
+  fn cross_correlation(&self, other: &Vector<T>, max_lag: usize) -> Result<Vector<T>, MathError> {
+    check_same_len(self, other)?;
+    let max_lag = max_lag as isize;
+    Ok(match lagged_correlation(self, other) {
+      Some(corr) => (-max_lag..=max_lag)
+        .map(corr)
+        .collect::<Vec<T>>()
+        .into_vector(),
+      None => vec![T::nan(); (2 * max_lag + 1) as usize].into_vector(),
+    })
+  }
+
+  fn ljung_box(&self, lags: usize) -> Result<LjungBox<T>, MathError> {
+    let n = self.iter().filter(|x| !x.is_nan()).count();
+    if lags == 0 || lags >= n {
+      return Err(MathError::InvalidArgument(format!(
+        "Ljung-Box lags must be between 1 and {}, found {}",
+        n.saturating_sub(1),
+        lags
+      )));
+    }
+    let acf = self.autocorrelation(lags);
+    let nt = T::from(n).unwrap();
+    let sum = (1..=lags).fold(T::zero(), |acc, k| {
+      acc + acf[k] * acf[k] / (nt - T::from(k).unwrap())
+    });
+    let statistic = nt * (nt + T::from(2.0).unwrap()) * sum;
+    let two = T::from(2.0).unwrap();
+    Ok(LjungBox {
+      statistic,
+      p_value: gamma_inc_upper(T::from(lags).unwrap() / two, statistic / two),
+      lags,
+    })
+  }
+}
+
+/// Returns a function of the lag `k` giving the correlation of `x[t]` with `y[t + k]`,
+/// or `None` if either series has an undefined or zero variance.
+fn lagged_correlation<'a, T>(x: &'a Vector<T>, y: &'a Vector<T>) -> Option<impl Fn(isize) -> T + 'a>
+where
+  T: Float + ToPrimitive + Copy + PartialOrd,
+{
+  let (mx, my) = (x.mean()?, y.mean()?);
+  let count = |v: &Vector<T>| T::from(v.iter().filter(|a| !a.is_nan()).count()).unwrap();
+  // Sums of squared deviations, count * var, for each series.
+  let ssx = x.var()? * count(x);
+  let ssy = y.var()? * count(y);
+  if ssx == T::zero() || ssy == T::zero() {
+    return None;
+  }
+  let denom = (ssx * ssy).sqrt();
+  let n = x.len().min(y.len()) as isize;
+  Some(move |k: isize| {
+    if k.abs() >= n {
+      return T::nan();
+    }
+    let (start, end) = if k >= 0 { (0, n - k) } else { (-k, n) };
+    let sum = (start..end).fold(T::zero(), |acc, t| {
+      let (a, b) = (x[t as usize], y[(t + k) as usize]);
+      if a.is_nan() || b.is_nan() {
+        acc
+      } else {
+        acc + (a - mx) * (b - my)
+      }
+    });
+    sum / denom
+  })
+}
@@ -0,0 +1,139 @@
+//! Numerical integration and differentiation of sampled data, following numpy's
+//! `gradient` and scipy's `trapezoid`, `simpson` and `cumulative_trapezoid`.
+//!
+//! NaN values propagate to every result they contribute to.
+
+use crate::error::{check_same_len, MathError};
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::Float;
+
+/// Spacing of the sample points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Spacing<'a, T> {
+  /// Evenly spaced samples, `dx` apart.
+  Uniform(T),
+  /// Samples at the given points, which must have the same length as the data.
+  Points(&'a Vector<T>),
+}
+
+/// Trait providing integration and differentiation of sampled `Vector<T>` data.
+pub trait Calculus<T> {
+  /// Integrates with the composite trapezoidal rule. Fewer than two samples give zero.
+  fn trapezoid(&self, spacing: Spacing<'_, T>) -> Result<T, MathError>;
+
+  /// Integrates with the composite Simpson's rule, which is exact for quadratics. For an
+  /// even number of samples the last interval uses Cartwright's correction, as scipy does.
+  /// Two samples fall back to the trapezoidal rule, and fewer give zero.
+  fn simpson(&self, spacing: Spacing<'_, T>) -> Result<T, MathError>;
+
+  /// Computes the running trapezoidal integral. The first element is zero, so the result
+  /// has the input length.
+  fn cumulative_trapezoid(&self, spacing: Spacing<'_, T>) -> Result<Vector<T>, MathError>;
+
+  /// Estimates the derivative with second-order central differences in the interior and
+  /// first-order one-sided differences at the edges, as numpy's `gradient` does.
+  /// Returns an error if there are fewer than two samples.
+  fn gradient(&self, spacing: Spacing<'_, T>) -> Result<Vector<T>, MathError>;
+}
+
+impl<T> Calculus<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn trapezoid(&self, spacing: Spacing<'_, T>) -> Result<T, MathError> {
+    Ok(
+      self
+        .cumulative_trapezoid(spacing)?
+        .last()
+        .cloned()
+        .unwrap_or(T::zero()),
+    )
+  }
+
+  fn simpson(&self, spacing: Spacing<'_, T>) -> Result<T, MathError> {
+    let h = steps(self, spacing)?;
+    let n = self.len();
+    if n < 3 {
+      return self.trapezoid(spacing);
+    }
+    let y = self;
+    let six = T::from(6.0).unwrap();
+    let two = T::from(2.0).unwrap();
+    let three = T::from(3.0).unwrap();
+    // Pairs of intervals [i, i + 2] up to the last odd point.
+    let end = if n % 2 == 0 { n - 2 } else { n - 1 };
+    let mut total = T::zero();
+    for i in (0..end).step_by(2) {
+      let (h0, h1) = (h[i], h[i + 1]);
+      let hsum = h0 + h1;
+      let ratio = h0 / h1;
+      total = total
+        + hsum / six
+          * (y[i] * (two - ratio.recip())
+            + y[i + 1] * (hsum * hsum / (h0 * h1))
+            + y[i + 2] * (two - ratio));
+    }
+    if n % 2 == 0 {
+      // Cartwright's correction: integrate the last interval with the quadratic through
+      // the last three points.
+      let (hm2, hm1) = (h[n - 3], h[n - 2]);
+      let alpha = (two * hm1 * hm1 + three * hm1 * hm2) / (six * (hm2 + hm1));
+      let beta = (hm1 * hm1 + three * hm1 * hm2) / (six * hm2);
+      let eta = hm1 * hm1 * hm1 / (six * hm2 * (hm2 + hm1));
+      total = total + alpha * y[n - 1] + beta * y[n - 2] - eta * y[n - 3];
+    }
+    Ok(total)
+  }
+
+  fn cumulative_trapezoid(&self, spacing: Spacing<'_, T>) -> Result<Vector<T>, MathError> {
+    let h = steps(self, spacing)?;
+    let half = T::from(0.5).unwrap();
+    let mut total = T::zero();
+    let mut result = Vec::with_capacity(self.len());
+    if !self.is_empty() {
+      result.push(total);
+    }
+    for (i, &hi) in h.iter().enumerate() {
+      total = total + (self[i] + self[i + 1]) * hi * half;
+      result.push(total);
+    }
+    Ok(result.into_vector())
+  }
+
+  fn gradient(&self, spacing: Spacing<'_, T>) -> Result<Vector<T>, MathError> {
+    let n = self.len();
+    if n < 2 {
+      return Err(MathError::InvalidArgument(
+        "gradient requires at least two samples".to_string(),
+      ));
+    }
+    let h = steps(self, spacing)?;
+    let y = self;
+    let mut result = Vec::with_capacity(n);
+    result.push((y[1] - y[0]) / h[0]);
+    for i in 1..n - 1 {
+      let (hs, hd) = (h[i - 1], h[i]);
+      // Weights of the second-order difference on a non-uniform grid; for hs == hd
+      // this reduces to (y[i + 1] - y[i - 1]) / (2 h).
+      let a = -hd / (hs * (hd + hs));
+      let b = (hd - hs) / (hd * hs);
+      let c = hs / (hd * (hd + hs));
+      result.push(a * y[i - 1] + b * y[i] + c * y[i + 1]);
+    }
+    result.push((y[n - 1] - y[n - 2]) / h[n - 2]);
+    Ok(result.into_vector())
+  }
+}
+
+/// Widths of the intervals between consecutive samples.
+fn steps<T: Float>(y: &[T], spacing: Spacing<'_, T>) -> Result<Vec<T>, MathError> {
+  let intervals = y.len().saturating_sub(1);
+  match spacing {
+    Spacing::Uniform(dx) => Ok(vec![dx; intervals]),
+    Spacing::Points(x) => {
+      check_same_len(x, y)?;
+      Ok(x.windows(2).map(|w| w[1] - w[0]).collect())
+    }
+  }
+}
@@ -0,0 +1,265 @@
+//! Column-wise statistics, covariance and correlation matrices for multi-feature data.
+//!
+//! Data can be held either in a `Matrix<T>`, where each column is a feature, or in a
+//! slice or `Vec` of `Vector<T>`, where each vector is one feature column.
+
+use crate::error::MathError;
+use crate::matrix::Matrix;
+use crate::statistics::Statistics;
+use crate::summary::{ColumnSummary, SummaryOps};
+use crate::vector::Vector;
+use num_traits::Float;
+use std::fmt;
+
+/// Trait providing per-column statistics and cross-column measures.
+///
+/// Per-column results are returned as a `Vector<T>` with one entry per column, where
+/// NaN stands in for a statistic that is undefined for that column (for example the
+/// mean of a column holding only NaN values). As with `Statistics`, NaN values are
+/// ignored, and the variance divides by the number of values.
+pub trait ColumnStatistics<T> {
+  /// Returns the feature columns as vectors.
+  fn column_vectors(&self) -> Vec<Vector<T>>;
+
+  /// Computes the mean of each column.
+  fn col_mean(&self) -> Vector<T>;
+
+  /// Computes the variance of each column.
+  fn col_var(&self) -> Vector<T>;
+
+  /// Computes the standard deviation of each column.
+  fn col_stddev(&self) -> Vector<T>;
+
+  /// Computes the median of each column.
+  fn col_median(&self) -> Vector<T>;
+
+  /// Computes the quantile `q` of each column.
+  fn col_quantile(&self, q: T) -> Vector<T>;
+
+  /// Returns the minimum of each column.
+  fn col_min(&self) -> Vector<T>;
+
+  /// Returns the maximum of each column.
+  fn col_max(&self) -> Vector<T>;
+
+  /// Computes the covariance matrix. Entry `(i, j)` is the covariance of columns
+  /// `i` and `j` over the rows where both are non-NaN, so the diagonal matches `col_var`.
+  /// Returns an error if the columns do not all have the same length.
+  fn covariance(&self) -> Result<Matrix<T>, MathError>;
+
+  /// Computes the Pearson correlation matrix, using pairwise complete rows as in `covariance`.
+  fn pearson_correlation(&self) -> Result<Matrix<T>, MathError>;
+
+  /// Computes the Spearman rank correlation matrix: the Pearson correlation of the ranks,
+  /// with tied values given their average rank.
+  fn spearman_correlation(&self) -> Result<Matrix<T>, MathError>;
+
+  /// Builds a summary table with one column per feature, similar to pandas `describe()`.
+  fn describe(&self) -> ColumnSummary<T>;
+}
+
+impl<T> ColumnStatistics<T> for Matrix<T>
+where
+  T: Float + Copy + PartialOrd + fmt::Display,
+{
+  fn column_vectors(&self) -> Vec<Vector<T>> {
+    self.cols()
+  }
+
+  fn col_mean(&self) -> Vector<T> {
+    self.column_vectors().as_slice().col_mean()
+  }
+
+  fn col_var(&self) -> Vector<T> {
+    self.column_vectors().as_slice().col_var()
+  }
+
+  fn col_stddev(&self) -> Vector<T> {
+    self.column_vectors().as_slice().col_stddev()
+  }
+
+  fn col_median(&self) -> Vector<T> {
+    self.column_vectors().as_slice().col_median()
+  }
+
+  fn col_quantile(&self, q: T) -> Vector<T> {
+    self.column_vectors().as_slice().col_quantile(q)
+  }
+
+  fn col_min(&self) -> Vector<T> {
+    self.column_vectors().as_slice().col_min()
+  }
+
+  fn col_max(&self) -> Vector<T> {
+    self.column_vectors().as_slice().col_max()
+  }
+
+  fn covariance(&self) -> Result<Matrix<T>, MathError> {
+    self.column_vectors().as_slice().covariance()
+  }
+
+  fn pearson_correlation(&self) -> Result<Matrix<T>, MathError> {
+    self.column_vectors().as_slice().pearson_correlation()
+  }
+
+  fn spearman_correlation(&self) -> Result<Matrix<T>, MathError> {
+    self.column_vectors().as_slice().spearman_correlation()
+  }
+
+  fn describe(&self) -> ColumnSummary<T> {
+    self.column_vectors().as_slice().describe()
+  }
+}
+
+impl<T> ColumnStatistics<T> for [Vector<T>]
+where
+  T: Float + Copy + PartialOrd + fmt::Display,
+{
+  fn column_vectors(&self) -> Vec<Vector<T>> {
+    self.to_vec()
+  }
+
+  fn col_mean(&self) -> Vector<T> {
+    per_column(self, |c| c.mean())
+  }
+
+  fn col_var(&self) -> Vector<T> {
+    per_column(self, |c| c.var())
+  }
+
+  fn col_stddev(&self) -> Vector<T> {
+    per_column(self, |c| c.stddev())
+  }
+
+  fn col_median(&self) -> Vector<T> {
+    per_column(self, |c| c.median())
+  }
+
+  fn col_quantile(&self, q: T) -> Vector<T> {
+    per_column(self, |c| c.quantile(q))
+  }
+
+  fn col_min(&self) -> Vector<T> {
+    per_column(self, |c| c.min())
+  }
+
+  fn col_max(&self) -> Vector<T> {
+    per_column(self, |c| c.max())
+  }
+
+  fn covariance(&self) -> Result<Matrix<T>, MathError> {
+    pairwise(self, |x, y| {
+      if x.len() < 2 {
+        return T::nan();
+      }
+      let (mx, my) = (mean(x), mean(y));
+      let sxy = x
+        .iter()
+        .zip(y.iter())
+        .fold(T::zero(), |acc, (&a, &b)| acc + (a - mx) * (b - my));
+      sxy / T::from(x.len()).unwrap()
+    })
+  }
+
+  fn pearson_correlation(&self) -> Result<Matrix<T>, MathError> {
+    pairwise(self, pearson)
+  }
+
+  fn spearman_correlation(&self) -> Result<Matrix<T>, MathError> {
+    pairwise(self, |x, y| pearson(&average_ranks(x), &average_ranks(y)))
+  }
+
+  fn describe(&self) -> ColumnSummary<T> {
+    ColumnSummary::new(self.iter().map(|c| c.summary()).collect())
+  }
+}
+
+/// Applies a statistic to each column, mapping `None` to NaN.
+fn per_column<T, F>(cols: &[Vector<T>], f: F) -> Vector<T>
+where
+  T: Float,
+  F: Fn(&Vector<T>) -> Option<T>,
+{
+  Vector::new(cols.iter().map(|c| f(c).unwrap_or(T::nan())).collect())
+}
+
+/// Builds a symmetric matrix by applying `f` to the pairwise complete (both non-NaN)
+/// values of every pair of columns.
+fn pairwise<T, F>(cols: &[Vector<T>], f: F) -> Result<Matrix<T>, MathError>
+where
+  T: Float,
+  F: Fn(&[T], &[T]) -> T,
+{
+  let k = cols.len();
+  let n = cols.first().map_or(0, |c| c.len());
+  if let Some(c) = cols.iter().find(|c| c.len() != n) {
+    return Err(MathError::LengthMismatch {
+      expected: n,
+      found: c.len(),
+    });
+  }
+  let mut out = Matrix::zeros(k, k);
+  for i in 0..k {
+    for j in i..k {
+      let (x, y): (Vec<T>, Vec<T>) = cols[i]
+        .iter()
+        .zip(cols[j].iter())
+        .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+        .map(|(&a, &b)| (a, b))
+        .unzip();
+      let v = f(&x, &y);
+      out[(i, j)] = v;
+      out[(j, i)] = v;
+    }
+  }
+  Ok(out)
+}
+
+/// Mean of a slice with no NaN values.
+fn mean<T: Float>(x: &[T]) -> T {
+  x.iter().fold(T::zero(), |acc, &v| acc + v) / T::from(x.len()).unwrap()
+}
+
+/// Pearson correlation of two equal-length slices with no NaN values.
+/// Returns NaN for fewer than two values or a constant input.
+fn pearson<T: Float>(x: &[T], y: &[T]) -> T {
+  if x.len() < 2 {
+    return T::nan();
+  }
+  let (mx, my) = (mean(x), mean(y));
+  let (mut sxy, mut sxx, mut syy) = (T::zero(), T::zero(), T::zero());
+  for (&a, &b) in x.iter().zip(y.iter()) {
+    let (da, db) = (a - mx, b - my);
+    sxy = sxy + da * db;
+    sxx = sxx + da * da;
+    syy = syy + db * db;
+  }
+  let denom = (sxx * syy).sqrt();
+  if denom == T::zero() {
+    T::nan()
+  } else {
+    (sxy / denom).max(-T::one()).min(T::one())
+  }
+}
+
+/// Ranks the values of a slice with no NaN values, starting from 1.
+/// Tied values all receive the average of the ranks they span.
+pub(crate) fn average_ranks<T: Float>(x: &[T]) -> Vec<T> {
+  let mut order: Vec<usize> = (0..x.len()).collect();
+  order.sort_by(|&a, &b| x[a].partial_cmp(&x[b]).unwrap());
+  let mut ranks = vec![T::zero(); x.len()];
+  let mut start = 0;
+  while start < order.len() {
+    let mut end = start + 1;
+    while end < order.len() && x[order[end]] == x[order[start]] {
+      end += 1;
+    }
+    // Positions start..end hold ranks start+1..=end, whose average is (start + end + 1) / 2.
+    let rank = T::from(start + end + 1).unwrap() / T::from(2.0).unwrap();
+    for &idx in &order[start..end] {
+      ranks[idx] = rank;
+    }
+    start = end;
+  }
+  ranks
+}
@@ -0,0 +1,154 @@
+//! Discrete linear convolution and cross-correlation of `Vector<T>`, following numpy's
+//! `convolve` and `correlate`.
+//!
+//! Results are computed either directly in O(n m) or through the FFT in
+//! O((n + m) log(n + m)). NaN and infinite values propagate to every output they
+//! contribute to, except with [`ConvolveMethod::Fft`], where they make the whole output NaN.
+
+use crate::fft::{fft, ifft, Complex};
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::{Float, FloatConst};
+
+/// Kernels up to this length are convolved directly by [`ConvolveMethod::Auto`].
+const DIRECT_MAX_KERNEL: usize = 64;
+
+/// Which part of the full convolution to return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvolveMode {
+  /// Every point of overlap, of length `n + m - 1`.
+  Full,
+  /// The centre of the full result, of length `max(n, m)`.
+  Same,
+  /// Only points where the inputs overlap completely, of length `max(n, m) - min(n, m) + 1`.
+  Valid,
+}
+
+/// How the convolution is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvolveMethod {
+  /// Direct summation for short kernels or inputs with non-finite values, the FFT
+  /// otherwise.
+  Auto,
+  /// Direct summation, exact for integer-valued data.
+  Direct,
+  /// Multiplication in the frequency domain. A single NaN or infinite input value
+  /// makes every output NaN.
+  Fft,
+}
+
+/// Trait providing convolution and cross-correlation for `Vector<T>`.
+pub trait Convolution<T> {
+  /// Convolves with `other`, choosing the method automatically.
+  /// Returns an empty vector if either input is empty.
+  fn convolve(&self, other: &Vector<T>, mode: ConvolveMode) -> Vector<T>;
+
+  /// Convolves with `other` using the given method.
+  fn convolve_with(
+    &self,
+    other: &Vector<T>,
+    mode: ConvolveMode,
+    method: ConvolveMethod,
+  ) -> Vector<T>;
+
+  /// Cross-correlates with `other`: entry `k` of the full result is
+  /// `sum_j self[j + k - (m - 1)] * other[j]`, where `m` is the length of `other`.
+  /// Returns an empty vector if either input is empty.
+  fn correlate(&self, other: &Vector<T>, mode: ConvolveMode) -> Vector<T>;
+
+  /// Cross-correlates with `other` using the given method.
+  fn correlate_with(
+    &self,
+    other: &Vector<T>,
+    mode: ConvolveMode,
+    method: ConvolveMethod,
+  ) -> Vector<T>;
+}
+
+impl<T> Convolution<T> for Vector<T>
+where
+  T: Float + FloatConst,
+{
+  fn convolve(&self, other: &Vector<T>, mode: ConvolveMode) -> Vector<T> {
+    self.convolve_with(other, mode, ConvolveMethod::Auto)
+  }
+
+  fn convolve_with(
+    &self,
+    other: &Vector<T>,
+    mode: ConvolveMode,
+    method: ConvolveMethod,
+  ) -> Vector<T> {
+    let (n, m) = (self.len(), other.len());
+    if n == 0 || m == 0 {
+      return Vector::new(Vec::new());
+    }
+    let use_fft = match method {
+      ConvolveMethod::Auto => {
+        n.min(m) > DIRECT_MAX_KERNEL && self.iter().chain(other.iter()).all(|x| x.is_finite())
+      }
+      ConvolveMethod::Direct => false,
+      ConvolveMethod::Fft => true,
+    };
+    let full = if use_fft {
+      fft_convolve(self, other)
+    } else {
+      direct_convolve(self, other)
+    };
+    let (start, len) = match mode {
+      ConvolveMode::Full => (0, n + m - 1),
+      ConvolveMode::Same => ((n.min(m) - 1) / 2, n.max(m)),
+      ConvolveMode::Valid => (n.min(m) - 1, n.max(m) - n.min(m) + 1),
+    };
+    full[start..start + len].to_vec().into_vector()
+  }
+
+  fn correlate(&self, other: &Vector<T>, mode: ConvolveMode) -> Vector<T> {
+    self.correlate_with(other, mode, ConvolveMethod::Auto)
+  }
+
+  fn correlate_with(
+    &self,
+    other: &Vector<T>,
+    mode: ConvolveMode,
+    method: ConvolveMethod,
+  ) -> Vector<T> {
+    let reversed = other
+      .iter()
+      .rev()
+      .cloned()
+      .collect::<Vec<T>>()
+      .into_vector();
+    self.convolve_with(&reversed, mode, method)
+  }
+}
+
+/// Full convolution by direct summation.
+fn direct_convolve<T: Float>(a: &[T], b: &[T]) -> Vec<T> {
+  let mut out = vec![T::zero(); a.len() + b.len() - 1];
+  for (i, &x) in a.iter().enumerate() {
+    for (j, &y) in b.iter().enumerate() {
+      out[i + j] = out[i + j] + x * y;
+    }
+  }
+  out
+}
+
+/// Full convolution through the FFT, zero-padding both inputs to a power of two.
+fn fft_convolve<T: Float + FloatConst>(a: &[T], b: &[T]) -> Vec<T> {
+  let len = a.len() + b.len() - 1;
+  let size = len.next_power_of_two();
+  let pad = |x: &[T]| {
+    let mut padded = vec![Complex::new(T::zero(), T::zero()); size];
+    for (p, &v) in padded.iter_mut().zip(x) {
+      p.re = v;
+    }
+    fft(&padded)
+  };
+  let product: Vec<Complex<T>> = pad(a)
+    .iter()
+    .zip(pad(b).iter())
+    .map(|(x, y)| x * y)
+    .collect();
+  ifft(&product)[..len].iter().map(|z| z.re).collect()
+}
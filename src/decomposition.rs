@@ -0,0 +1,491 @@
+//! Matrix decompositions and linear solvers for `Matrix<T>`.
+//!
+//! - [`Lu`]: LU decomposition with partial pivoting, for square systems, determinants and inverses.
+//! - [`Cholesky`]: Cholesky decomposition of symmetric positive-definite matrices.
+//! - [`Qr`]: Householder QR decomposition, for least-squares problems.
+//! - [`SymmetricEigen`]: eigenvalues and eigenvectors of symmetric matrices.
+
+use crate::error::MathError;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use num_traits::Float;
+
+/// LU decomposition with partial pivoting, `P * A = L * U`.
+#[derive(Debug, Clone)]
+pub struct Lu<T> {
+  /// `L` below the diagonal (its unit diagonal is implicit) and `U` on and above it.
+  lu: Matrix<T>,
+  /// Row `i` of `P * A` is row `perm[i]` of `A`.
+  perm: Vec<usize>,
+  /// `1` or `-1` depending on the parity of the permutation.
+  sign: T,
+}
+
+/// Cholesky decomposition `A = L * L^T` of a symmetric positive-definite matrix.
+#[derive(Debug, Clone)]
+pub struct Cholesky<T> {
+  l: Matrix<T>,
+}
+
+/// Householder QR decomposition `A = Q * R` of an `m x n` matrix with `m >= n`.
+#[derive(Debug, Clone)]
+pub struct Qr<T> {
+  /// Householder vectors on and below the diagonal, `R` above it.
+  qr: Matrix<T>,
+  /// Diagonal of `R`.
+  r_diag: Vec<T>,
+}
+
+/// Eigendecomposition `A = V * diag(values) * V^T` of a symmetric matrix,
+/// computed with the cyclic Jacobi method.
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T> {
+  /// Eigenvalues in descending order.
+  pub values: Vector<T>,
+  /// Unit eigenvectors as columns, in the same order as `values`.
+  pub vectors: Matrix<T>,
+}
+
+impl<T> Lu<T>
+where
+  T: Float + Copy,
+{
+  /// Computes the LU decomposition of a square matrix.
+  /// Singular matrices decompose successfully; the error surfaces in `solve` and `inverse`.
+  pub fn new(a: &Matrix<T>) -> Result<Self, MathError> {
+    require_square(a)?;
+    let n = a.nrows();
+    let mut lu = a.clone();
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut sign = T::one();
+
+    for k in 0..n {
+      let pivot = (k..n)
+        .max_by(|&i, &j| {
+          lu[(i, k)]
+            .abs()
+            .partial_cmp(&lu[(j, k)].abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap_or(k);
+      if pivot != k {
+        for j in 0..n {
+          let tmp = lu[(k, j)];
+          lu[(k, j)] = lu[(pivot, j)];
+          lu[(pivot, j)] = tmp;
+        }
+        perm.swap(k, pivot);
+        sign = -sign;
+      }
+      let diag = lu[(k, k)];
+      if diag == T::zero() {
+        continue;
+      }
+      for i in k + 1..n {
+        let factor = lu[(i, k)] / diag;
+        lu[(i, k)] = factor;
+        for j in k + 1..n {
+          lu[(i, j)] = lu[(i, j)] - factor * lu[(k, j)];
+        }
+      }
+    }
+    Ok(Lu { lu, perm, sign })
+  }
+
+  /// Returns the unit lower-triangular factor `L`.
+  pub fn l(&self) -> Matrix<T> {
+    let n = self.lu.nrows();
+    Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+      std::cmp::Ordering::Greater => self.lu[(i, j)],
+      std::cmp::Ordering::Equal => T::one(),
+      std::cmp::Ordering::Less => T::zero(),
+    })
+  }
+
+  /// Returns the upper-triangular factor `U`.
+  pub fn u(&self) -> Matrix<T> {
+    let n = self.lu.nrows();
+    Matrix::from_fn(
+      n,
+      n,
+      |i, j| if i <= j { self.lu[(i, j)] } else { T::zero() },
+    )
+  }
+
+  /// Returns the row permutation: row `i` of `P * A` is row `permutation()[i]` of `A`.
+  pub fn permutation(&self) -> &[usize] {
+    &self.perm
+  }
+
+  /// Returns `true` if `U` has a diagonal element that is zero relative to the others.
+  pub fn is_singular(&self) -> bool {
+    let n = self.lu.nrows();
+    let diag: Vec<T> = (0..n).map(|i| self.lu[(i, i)].abs()).collect();
+    let scale = diag.iter().fold(T::zero(), |m, &d| m.max(d));
+    let tol = scale * T::epsilon() * T::from(n).unwrap();
+    scale == T::zero() || diag.iter().any(|&d| d <= tol)
+  }
+
+  /// Computes the determinant of the decomposed matrix.
+  pub fn determinant(&self) -> T {
+    (0..self.lu.nrows()).fold(self.sign, |det, i| det * self.lu[(i, i)])
+  }
+
+  /// Solves `A * x = b` for `x`.
+  pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+    let n = self.lu.nrows();
+    if b.len() != n {
+      return Err(MathError::LengthMismatch {
+        expected: n,
+        found: b.len(),
+      });
+    }
+    if self.is_singular() {
+      return Err(MathError::Singular);
+    }
+    let mut x: Vec<T> = self.perm.iter().map(|&p| b[p]).collect();
+    for i in 0..n {
+      for k in 0..i {
+        x[i] = x[i] - self.lu[(i, k)] * x[k];
+      }
+    }
+    for i in (0..n).rev() {
+      for k in i + 1..n {
+        x[i] = x[i] - self.lu[(i, k)] * x[k];
+      }
+      x[i] = x[i] / self.lu[(i, i)];
+    }
+    Ok(Vector::new(x))
+  }
+
+  /// Computes the inverse of the decomposed matrix.
+  pub fn inverse(&self) -> Result<Matrix<T>, MathError> {
+    let n = self.lu.nrows();
+    let mut inv = Matrix::zeros(n, n);
+    for j in 0..n {
+      let mut e = Vector::new(vec![T::zero(); n]);
+      e[j] = T::one();
+      let col = self.solve(&e)?;
+      for i in 0..n {
+        inv[(i, j)] = col[i];
+      }
+    }
+    Ok(inv)
+  }
+}
+
+impl<T> Cholesky<T>
+where
+  T: Float + Copy,
+{
+  /// Computes the Cholesky decomposition of a symmetric positive-definite matrix.
+  /// Only the lower triangle of `a` is read.
+  pub fn new(a: &Matrix<T>) -> Result<Self, MathError> {
+    require_square(a)?;
+    let n = a.nrows();
+    let mut l = Matrix::zeros(n, n);
+    for j in 0..n {
+      let mut d = a[(j, j)];
+      for k in 0..j {
+        d = d - l[(j, k)] * l[(j, k)];
+      }
+      if d.is_nan() || d <= T::zero() {
+        return Err(MathError::NotPositiveDefinite);
+      }
+      let d = d.sqrt();
+      l[(j, j)] = d;
+      for i in j + 1..n {
+        let mut s = a[(i, j)];
+        for k in 0..j {
+          s = s - l[(i, k)] * l[(j, k)];
+        }
+        l[(i, j)] = s / d;
+      }
+    }
+    Ok(Cholesky { l })
+  }
+
+  /// Returns the lower-triangular factor `L`.
+  pub fn l(&self) -> &Matrix<T> {
+    &self.l
+  }
+
+  /// Computes the determinant of the decomposed matrix.
+  pub fn determinant(&self) -> T {
+    let d = self.l.diagonal().iter().fold(T::one(), |acc, &x| acc * x);
+    d * d
+  }
+
+  /// Solves `A * x = b` for `x`.
+  pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+    let n = self.l.nrows();
+    if b.len() != n {
+      return Err(MathError::LengthMismatch {
+        expected: n,
+        found: b.len(),
+      });
+    }
+    let l = &self.l;
+    let mut x = b.clone();
+    for i in 0..n {
+      for k in 0..i {
+        x[i] = x[i] - l[(i, k)] * x[k];
+      }
+      x[i] = x[i] / l[(i, i)];
+    }
+    for i in (0..n).rev() {
+      for k in i + 1..n {
+        x[i] = x[i] - l[(k, i)] * x[k];
+      }
+      x[i] = x[i] / l[(i, i)];
+    }
+    Ok(x)
+  }
+}
+
+impl<T> Qr<T>
+where
+  T: Float + Copy,
+{
+  /// Computes the QR decomposition of an `m x n` matrix with `m >= n`.
+  pub fn new(a: &Matrix<T>) -> Result<Self, MathError> {
+    let (m, n) = a.shape();
+    if m < n {
+      return Err(MathError::ShapeMismatch {
+        expected: (n, n),
+        found: (m, n),
+      });
+    }
+    let mut qr = a.clone();
+    let mut r_diag = vec![T::zero(); n];
+    for k in 0..n {
+      let mut nrm = (k..m).fold(T::zero(), |acc, i| acc.hypot(qr[(i, k)]));
+      if nrm != T::zero() {
+        if qr[(k, k)] < T::zero() {
+          nrm = -nrm;
+        }
+        for i in k..m {
+          qr[(i, k)] = qr[(i, k)] / nrm;
+        }
+        qr[(k, k)] = qr[(k, k)] + T::one();
+        for j in k + 1..n {
+          let s = (k..m).fold(T::zero(), |acc, i| acc + qr[(i, k)] * qr[(i, j)]);
+          let s = -s / qr[(k, k)];
+          for i in k..m {
+            qr[(i, j)] = qr[(i, j)] + s * qr[(i, k)];
+          }
+        }
+      }
+      r_diag[k] = -nrm;
+    }
+    Ok(Qr { qr, r_diag })
+  }
+
+  /// Returns the `m x n` factor `Q` with orthonormal columns.
+  pub fn q(&self) -> Matrix<T> {
+    let (m, n) = self.qr.shape();
+    let mut q = Matrix::zeros(m, n);
+    for k in (0..n).rev() {
+      q[(k, k)] = T::one();
+      for j in k..n {
+        if self.qr[(k, k)] != T::zero() {
+          let s = (k..m).fold(T::zero(), |acc, i| acc + self.qr[(i, k)] * q[(i, j)]);
+          let s = -s / self.qr[(k, k)];
+          for i in k..m {
+            q[(i, j)] = q[(i, j)] + s * self.qr[(i, k)];
+          }
+        }
+      }
+    }
+    q
+  }
+
+  /// Returns the `n x n` upper-triangular factor `R`.
+  pub fn r(&self) -> Matrix<T> {
+    let n = self.qr.ncols();
+    Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+      std::cmp::Ordering::Less => self.qr[(i, j)],
+      std::cmp::Ordering::Equal => self.r_diag[i],
+      std::cmp::Ordering::Greater => T::zero(),
+    })
+  }
+
+  /// Returns `true` if the columns of the decomposed matrix are linearly independent.
+  pub fn is_full_rank(&self) -> bool {
+    let scale = self.r_diag.iter().fold(T::zero(), |m, &d| m.max(d.abs()));
+    let tol = scale * T::epsilon() * T::from(self.qr.nrows()).unwrap();
+    scale != T::zero() && self.r_diag.iter().all(|&d| d.abs() > tol)
+  }
+
+  /// Finds the `x` minimizing `||A * x - b||`, which solves `A * x = b` exactly when `A` is square.
+  /// Returns an error if `A` is rank deficient.
+  pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+    let (m, n) = self.qr.shape();
+    if b.len() != m {
+      return Err(MathError::LengthMismatch {
+        expected: m,
+        found: b.len(),
+      });
+    }
+    if !self.is_full_rank() {
+      return Err(MathError::Singular);
+    }
+    let qr = &self.qr;
+    let mut x = b.clone();
+    // Apply Q^T to b.
+    for k in 0..n {
+      let s = (k..m).fold(T::zero(), |acc, i| acc + qr[(i, k)] * x[i]);
+      let s = -s / qr[(k, k)];
+      for i in k..m {
+        x[i] = x[i] + s * qr[(i, k)];
+      }
+    }
+    // Back-substitute R * x = Q^T * b.
+    for k in (0..n).rev() {
+      x[k] = x[k] / self.r_diag[k];
+      for i in 0..k {
+        x[i] = x[i] - x[k] * qr[(i, k)];
+      }
+    }
+    x.truncate(n);
+    Ok(x)
+  }
+}
+
+impl<T> SymmetricEigen<T>
+where
+  T: Float + Copy,
+{
+  /// Maximum number of Jacobi sweeps before giving up on convergence.
+  const MAX_SWEEPS: usize = 100;
+
+  /// Computes the eigendecomposition of a symmetric matrix.
+  /// Only the upper triangle of `a` is read. Each eigenvector is signed so that its
+  /// largest-magnitude component is positive, which makes the result deterministic.
+  pub fn new(a: &Matrix<T>) -> Result<Self, MathError> {
+    require_square(a)?;
+    let n = a.nrows();
+    let mut m = Matrix::from_fn(n, n, |i, j| if i <= j { a[(i, j)] } else { a[(j, i)] });
+    let mut v = Matrix::identity(n);
+    let two = T::from(2.0).unwrap();
+    let scale = m.as_slice().iter().fold(T::zero(), |acc, &x| acc.hypot(x));
+
+    for _ in 0..Self::MAX_SWEEPS {
+      let off = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+        .fold(T::zero(), |acc, (i, j)| acc.hypot(m[(i, j)]));
+      if off <= T::epsilon() * scale {
+        break;
+      }
+      for p in 0..n {
+        for q in p + 1..n {
+          let apq = m[(p, q)];
+          if apq == T::zero() {
+            continue;
+          }
+          let theta = (m[(q, q)] - m[(p, p)]) / (two * apq);
+          let t = theta.signum() / (theta.abs() + theta.hypot(T::one()));
+          let c = T::one() / t.hypot(T::one());
+          let s = t * c;
+          for k in 0..n {
+            let (mkp, mkq) = (m[(k, p)], m[(k, q)]);
+            m[(k, p)] = c * mkp - s * mkq;
+            m[(k, q)] = s * mkp + c * mkq;
+          }
+          for k in 0..n {
+            let (mpk, mqk) = (m[(p, k)], m[(q, k)]);
+            m[(p, k)] = c * mpk - s * mqk;
+            m[(q, k)] = s * mpk + c * mqk;
+          }
+          for k in 0..n {
+            let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+            v[(k, p)] = c * vkp - s * vkq;
+            v[(k, q)] = s * vkp + c * vkq;
+          }
+        }
+      }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+      m[(j, j)]
+        .partial_cmp(&m[(i, i)])
+        .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let values = Vector::new(order.iter().map(|&i| m[(i, i)]).collect());
+    let mut vectors = Matrix::from_fn(n, n, |i, j| v[(i, order[j])]);
+    for j in 0..n {
+      let col = vectors.col(j);
+      let pivot = col.iter().copied().fold(
+        T::zero(),
+        |best, x| if x.abs() > best.abs() { x } else { best },
+      );
+      if pivot < T::zero() {
+        for i in 0..n {
+          vectors[(i, j)] = -vectors[(i, j)];
+        }
+      }
+    }
+    Ok(SymmetricEigen { values, vectors })
+  }
+}
+
+impl<T> Matrix<T>
+where
+  T: Float + Copy,
+{
+  /// Computes the LU decomposition with partial pivoting. See [`Lu`].
+  pub fn lu(&self) -> Result<Lu<T>, MathError> {
+    Lu::new(self)
+  }
+
+  /// Computes the Cholesky decomposition. See [`Cholesky`].
+  pub fn cholesky(&self) -> Result<Cholesky<T>, MathError> {
+    Cholesky::new(self)
+  }
+
+  /// Computes the Householder QR decomposition. See [`Qr`].
+  pub fn qr(&self) -> Result<Qr<T>, MathError> {
+    Qr::new(self)
+  }
+
+  /// Computes the eigendecomposition of a symmetric matrix. See [`SymmetricEigen`].
+  pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, MathError> {
+    SymmetricEigen::new(self)
+  }
+
+  /// Computes the determinant of a square matrix.
+  pub fn determinant(&self) -> Result<T, MathError> {
+    Ok(self.lu()?.determinant())
+  }
+
+  /// Computes the inverse of a square matrix.
+  /// Returns an error if the matrix is singular.
+  pub fn inverse(&self) -> Result<Matrix<T>, MathError> {
+    self.lu()?.inverse()
+  }
+
+  /// Solves the square system `self * x = b` for `x`.
+  /// Returns an error if the matrix is singular.
+  pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+    self.lu()?.solve(b)
+  }
+
+  /// Finds the least-squares solution `x` minimizing `||self * x - b||`.
+  /// The matrix needs at least as many rows as columns and full column rank.
+  pub fn lstsq(&self, b: &Vector<T>) -> Result<Vector<T>, MathError> {
+    self.qr()?.solve(b)
+  }
+}
+
+/// Returns `ShapeMismatch` unless `a` is square.
+fn require_square<T>(a: &Matrix<T>) -> Result<(), MathError> {
+  if a.is_square() {
+    Ok(())
+  } else {
+    Err(MathError::ShapeMismatch {
+      expected: (a.nrows(), a.nrows()),
+      found: a.shape(),
+    })
+  }
+}
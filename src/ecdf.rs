@@ -0,0 +1,150 @@
+//! Empirical cumulative distribution function of a `Vector<T>`.
+//!
+//! NaN values in the data are dropped. Evaluating at NaN gives NaN.
+
+use crate::error::MathError;
+use crate::statistics::{quantile_sorted, QuantileMethod};
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::Float;
+
+/// The empirical CDF of a sample: the fraction of values at or below each point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ecdf<T> {
+  /// Sorted sample values.
+  data: Vec<T>,
+}
+
+impl<T> Ecdf<T>
+where
+  T: Float,
+{
+  /// Builds the empirical CDF of the non-NaN values of `data`.
+  /// Returns an error if there are none.
+  pub fn new(data: &Vector<T>) -> Result<Self, MathError> {
+    let mut values: Vec<T> = data.iter().cloned().filter(|x| !x.is_nan()).collect();
+    if values.is_empty() {
+      return Err(MathError::InvalidArgument(
+        "an empirical CDF requires at least one non-NaN value".to_string(),
+      ));
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Ok(Ecdf { data: values })
+  }
+
+  /// Returns the sorted sample values.
+  pub fn values(&self) -> &[T] {
+    &self.data
+  }
+
+  /// Returns the number of sample values.
+  pub fn len(&self) -> usize {
+    self.data.len()
+  }
+
+  /// Always `false`, since an empirical CDF has at least one value.
+  pub fn is_empty(&self) -> bool {
+    self.data.is_empty()
+  }
+
+  /// Returns the fraction of values less than or equal to `x`.
+  pub fn eval(&self, x: T) -> T {
+    if x.is_nan() {
+      return x;
+    }
+    let count = self.data.partition_point(|&v| v <= x);
+    T::from(count).unwrap() / T::from(self.data.len()).unwrap()
+  }
+
+  /// Evaluates the empirical CDF at each point of `points`.
+  pub fn eval_many(&self, points: &Vector<T>) -> Vector<T> {
+    points
+      .iter()
+      .map(|&x| self.eval(x))
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// Returns the fraction of values greater than `x`, `1 - eval(x)`.
+  pub fn survival(&self, x: T) -> T {
+    T::one() - self.eval(x)
+  }
+
+  /// Evaluates the survival function at each point of `points`.
+  pub fn survival_many(&self, points: &Vector<T>) -> Vector<T> {
+    points
+      .iter()
+      .map(|&x| self.survival(x))
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// Returns the value at cumulative probability `p`, computed as
+  /// `Statistics::quantile_with` computes it for the same method.
+  /// `QuantileMethod::InvertedCdf` gives the exact inverse: the smallest value `x` with
+  /// `eval(x) >= p`. Returns None if `p` is outside [0, 1] or NaN.
+  pub fn inverse(&self, p: T, method: QuantileMethod) -> Option<T> {
+    quantile_sorted(&self.data, p, method)
+  }
+
+  /// Returns the half-width `sqrt(ln(2 / alpha) / (2 n))` of the Dvoretzky-Kiefer-Wolfowitz
+  /// confidence band, which contains the true CDF everywhere with probability at least
+  /// `1 - alpha`. Returns an error unless `alpha` is strictly between 0 and 1.
+  pub fn dkw_epsilon(&self, alpha: T) -> Result<T, MathError> {
+    if alpha.is_nan() || alpha <= T::zero() || alpha >= T::one() {
+      return Err(MathError::InvalidArgument(
+        "alpha must be strictly between 0 and 1".to_string(),
+      ));
+    }
+    let two = T::from(2.0).unwrap();
+    Ok(((two / alpha).ln() / (two * T::from(self.data.len()).unwrap())).sqrt())
+  }
+
+  /// Returns the lower and upper limits of the `1 - alpha` Dvoretzky-Kiefer-Wolfowitz
+  /// confidence band at each point of `points`, clipped to [0, 1]. NaN points give NaN.
+  /// Returns an error unless `alpha` is strictly between 0 and 1.
+  pub fn confidence_band(
+    &self,
+    points: &Vector<T>,
+    alpha: T,
+  ) -> Result<(Vector<T>, Vector<T>), MathError> {
+    let epsilon = self.dkw_epsilon(alpha)?;
+    let values = self.eval_many(points);
+    let lower = values
+      .iter()
+      .map(|&f| {
+        if f.is_nan() {
+          f
+        } else {
+          (f - epsilon).max(T::zero())
+        }
+      })
+      .collect::<Vec<T>>();
+    let upper = values
+      .iter()
+      .map(|&f| {
+        if f.is_nan() {
+          f
+        } else {
+          (f + epsilon).min(T::one())
+        }
+      })
+      .collect::<Vec<T>>();
+    Ok((lower.into_vector(), upper.into_vector()))
+  }
+}
+
+/// Trait providing the empirical CDF of `Vector<T>`.
+pub trait EcdfOps<T> {
+  /// Builds the empirical CDF of the values. See [`Ecdf::new`].
+  fn ecdf(&self) -> Result<Ecdf<T>, MathError>;
+}
+
+impl<T> EcdfOps<T> for Vector<T>
+where
+  T: Float,
+{
+  fn ecdf(&self) -> Result<Ecdf<T>, MathError> {
+    Ecdf::new(self)
+  }
+}
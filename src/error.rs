@@ -0,0 +1,63 @@
+//! Error type for fallible operations.
+
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by operations that can fail on malformed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MathError {
+  /// Two inputs that must have the same length do not.
+  LengthMismatch { expected: usize, found: usize },
+  /// An input does not have the length the operation requires.
+  InvalidLength { expected: usize, found: usize },
+  /// A matrix does not have the shape the operation requires.
+  ShapeMismatch {
+    expected: (usize, usize),
+    found: (usize, usize),
+  },
+  /// A matrix is singular (or rank deficient) where an invertible one is required.
+  Singular,
+  /// A matrix is not positive definite where a positive-definite one is required.
+  NotPositiveDefinite,
+  /// A parameter is outside its valid range.
+  InvalidArgument(String),
+}
+
+impl fmt::Display for MathError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      MathError::LengthMismatch { expected, found } => write!(
+        f,
+        "length mismatch: expected {} elements, found {}",
+        expected, found
+      ),
+      MathError::InvalidLength { expected, found } => write!(
+        f,
+        "invalid length: operation requires {} elements, found {}",
+        expected, found
+      ),
+      MathError::ShapeMismatch { expected, found } => write!(
+        f,
+        "shape mismatch: expected a {}x{} matrix, found {}x{}",
+        expected.0, expected.1, found.0, found.1
+      ),
+      MathError::Singular => write!(f, "matrix is singular"),
+      MathError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+      MathError::InvalidArgument(msg) => write!(f, "invalid argument: {}", msg),
+    }
+  }
+}
+
+impl Error for MathError {}
+
+/// Returns `LengthMismatch` unless `a` and `b` have the same length.
+pub(crate) fn check_same_len<A, B>(a: &[A], b: &[B]) -> Result<(), MathError> {
+  if a.len() == b.len() {
+    Ok(())
+  } else {
+    Err(MathError::LengthMismatch {
+      expected: a.len(),
+      found: b.len(),
+    })
+  }
+}
@@ -0,0 +1,218 @@
+//! Exponentially weighted statistics for `Vector<T>`, following the semantics of
+//! pandas' `ewm`.
+//!
+//! `ewm(decay)` returns an [`Ewm`] view configured with builder-style setters, whose
+//! methods produce a `Vector<T>` of the same length as the input.
+
+use crate::error::MathError;
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::Float;
+
+/// How quickly the weights of past observations decay. Each variant is converted to
+/// the smoothing factor `alpha` used by [`Ewm`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EwmDecay<T> {
+  /// Center of mass `com >= 0`: `alpha = 1 / (1 + com)`.
+  Com(T),
+  /// Span `span >= 1`: `alpha = 2 / (span + 1)`.
+  Span(T),
+  /// Half-life `halflife > 0`, in observations: `alpha = 1 - exp(ln(0.5) / halflife)`.
+  HalfLife(T),
+  /// Smoothing factor `0 < alpha <= 1`, used directly.
+  Alpha(T),
+}
+
+impl<T: Float> EwmDecay<T> {
+  /// Returns the smoothing factor, or an error if the parameter is out of range.
+  pub fn alpha(&self) -> Result<T, MathError> {
+    let invalid = |msg: &str| Err(MathError::InvalidArgument(msg.to_string()));
+    match *self {
+      EwmDecay::Com(com) if com >= T::zero() => Ok((T::one() + com).recip()),
+      EwmDecay::Com(_) => invalid("com must be at least 0"),
+      EwmDecay::Span(span) if span >= T::one() => Ok(T::from(2.0).unwrap() / (span + T::one())),
+      EwmDecay::Span(_) => invalid("span must be at least 1"),
+      EwmDecay::HalfLife(h) if h > T::zero() => {
+        Ok(T::one() - (T::from(0.5).unwrap().ln() / h).exp())
+      }
+      EwmDecay::HalfLife(_) => invalid("halflife must be positive"),
+      EwmDecay::Alpha(a) if a > T::zero() && a <= T::one() => Ok(a),
+      EwmDecay::Alpha(_) => invalid("alpha must be in (0, 1]"),
+    }
+  }
+}
+
+/// Trait providing exponentially weighted views over `Vector<T>`.
+pub trait EwmOps<T> {
+  /// Returns an exponentially weighted view with the given decay.
+  /// Returns an error if the decay parameter is out of range.
+  fn ewm(&self, decay: EwmDecay<T>) -> Result<Ewm<'_, T>, MathError>;
+}
+
+impl<T> EwmOps<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn ewm(&self, decay: EwmDecay<T>) -> Result<Ewm<'_, T>, MathError> {
+    Ok(Ewm {
+      data: self,
+      alpha: decay.alpha()?,
+      adjust: true,
+      ignore_na: false,
+      bias: false,
+      min_periods: 1,
+    })
+  }
+}
+
+/// An exponentially weighted view over a vector, created by [`EwmOps::ewm`].
+///
+/// The defaults match pandas: `adjust = true`, `ignore_na = false`, `bias = false`
+/// and `min_periods = 1`.
+#[derive(Debug, Clone, Copy)]
+pub struct Ewm<'a, T> {
+  data: &'a [T],
+  alpha: T,
+  adjust: bool,
+  ignore_na: bool,
+  bias: bool,
+  min_periods: usize,
+}
+
+impl<'a, T> Ewm<'a, T>
+where
+  T: Float + Copy,
+{
+  /// With `adjust`, each value is a weighted average with weights `(1 - alpha)^i`
+  /// normalized over the observations seen so far. Without it, the recursive form
+  /// `y_t = (1 - alpha) * y_{t-1} + alpha * x_t` is used.
+  pub fn adjust(mut self, adjust: bool) -> Self {
+    self.adjust = adjust;
+    self
+  }
+
+  /// With `ignore_na`, NaN values do not advance the decay; otherwise weights are
+  /// based on absolute positions, so older values decay across gaps.
+  pub fn ignore_na(mut self, ignore_na: bool) -> Self {
+    self.ignore_na = ignore_na;
+    self
+  }
+
+  /// With `bias`, `var` and `stddev` are the weighted population estimates; otherwise
+  /// they are corrected for bias using the effective number of observations.
+  pub fn bias(mut self, bias: bool) -> Self {
+    self.bias = bias;
+    self
+  }
+
+  /// Sets the minimum number of non-NaN values seen before a result is produced.
+  /// Values below 1 are treated as 1.
+  pub fn min_periods(mut self, min_periods: usize) -> Self {
+    self.min_periods = min_periods.max(1);
+    self
+  }
+
+  /// Computes the exponentially weighted moving average.
+  pub fn mean(&self) -> Vector<T> {
+    let (decay, new_wt) = self.weights();
+    let mut result = Vec::with_capacity(self.data.len());
+    let mut weighted = T::nan();
+    let mut old_wt = T::one();
+    let mut nobs = 0;
+    for &x in self.data {
+      let is_obs = !x.is_nan();
+      nobs += usize::from(is_obs);
+      if weighted.is_nan() {
+        weighted = x;
+      } else if is_obs || !self.ignore_na {
+        old_wt = old_wt * decay;
+        if is_obs {
+          if weighted != x {
+            weighted = (old_wt * weighted + new_wt * x) / (old_wt + new_wt);
+          }
+          old_wt = if self.adjust {
+            old_wt + new_wt
+          } else {
+            T::one()
+          };
+        }
+      }
+      result.push(if nobs >= self.min_periods {
+        weighted
+      } else {
+        T::nan()
+      });
+    }
+    result.into_vector()
+  }
+
+  /// Computes the exponentially weighted moving variance.
+  pub fn var(&self) -> Vector<T> {
+    let (decay, new_wt) = self.weights();
+    let mut result = Vec::with_capacity(self.data.len());
+    let mut mean = T::nan();
+    let mut cov = T::zero();
+    let (mut sum_wt, mut sum_wt2, mut old_wt) = (T::one(), T::one(), T::one());
+    let mut nobs = 0;
+    for &x in self.data {
+      let is_obs = !x.is_nan();
+      nobs += usize::from(is_obs);
+      if mean.is_nan() {
+        mean = x;
+      } else if is_obs || !self.ignore_na {
+        sum_wt = sum_wt * decay;
+        sum_wt2 = sum_wt2 * decay * decay;
+        old_wt = old_wt * decay;
+        if is_obs {
+          let old_mean = mean;
+          if mean != x {
+            mean = (old_wt * old_mean + new_wt * x) / (old_wt + new_wt);
+          }
+          let shift = old_mean - mean;
+          cov =
+            (old_wt * (cov + shift * shift) + new_wt * (x - mean) * (x - mean)) / (old_wt + new_wt);
+          sum_wt = sum_wt + new_wt;
+          sum_wt2 = sum_wt2 + new_wt * new_wt;
+          old_wt = old_wt + new_wt;
+          if !self.adjust {
+            sum_wt = sum_wt / old_wt;
+            sum_wt2 = sum_wt2 / (old_wt * old_wt);
+            old_wt = T::one();
+          }
+        }
+      }
+      let value = if nobs < self.min_periods {
+        T::nan()
+      } else if self.bias {
+        cov
+      } else {
+        // Divide by 1 - sum(w^2) / sum(w)^2, the weighted analogue of (n - 1) / n.
+        let numerator = sum_wt * sum_wt;
+        let denominator = numerator - sum_wt2;
+        if denominator > T::zero() {
+          numerator / denominator * cov
+        } else {
+          T::nan()
+        }
+      };
+      result.push(value);
+    }
+    result.into_vector()
+  }
+
+  /// Computes the exponentially weighted moving standard deviation, the square root of `var`.
+  pub fn stddev(&self) -> Vector<T> {
+    self
+      .var()
+      .iter()
+      .map(|v| v.sqrt())
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// Returns the per-step decay factor of older weights and the weight of a new value.
+  fn weights(&self) -> (T, T) {
+    let new_wt = if self.adjust { T::one() } else { self.alpha };
+    (T::one() - self.alpha, new_wt)
+  }
+}
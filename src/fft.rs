@@ -0,0 +1,158 @@
+//! Fast Fourier transforms.
+//!
+//! Power-of-two lengths use an iterative radix-2 Cooley-Tukey transform; other lengths
+//! use Bluestein's algorithm, which rewrites the transform as a power-of-two convolution.
+//! Both run in O(n log n). The forward transform is unnormalized and the inverse divides
+//! by `n`, matching numpy's `fft` and `ifft`.
+
+use crate::vector::Vector;
+use num_traits::{Float, FloatConst};
+
+pub use num_complex::Complex;
+
+/// Trait providing Fourier transforms of real `Vector<T>` data.
+pub trait FftOps<T> {
+  /// Computes the discrete Fourier transform of the vector.
+  fn fft(&self) -> Vec<Complex<T>>;
+
+  /// Computes the non-negative frequency terms of the transform, the first
+  /// `len / 2 + 1` entries of `fft`. The rest follow by conjugate symmetry.
+  fn rfft(&self) -> Vec<Complex<T>>;
+}
+
+impl<T> FftOps<T> for Vector<T>
+where
+  T: Float + FloatConst,
+{
+  fn fft(&self) -> Vec<Complex<T>> {
+    let data: Vec<Complex<T>> = self.iter().map(|&x| Complex::new(x, T::zero())).collect();
+    fft(&data)
+  }
+
+  fn rfft(&self) -> Vec<Complex<T>> {
+    let mut spectrum = self.fft();
+    spectrum.truncate(self.len() / 2 + 1);
+    spectrum
+  }
+}
+
+/// Computes the discrete Fourier transform `X[k] = sum_j x[j] * exp(-2 pi i j k / n)`.
+pub fn fft<T>(input: &[Complex<T>]) -> Vec<Complex<T>>
+where
+  T: Float + FloatConst,
+{
+  let mut data = input.to_vec();
+  transform(&mut data, false);
+  data
+}
+
+/// Computes the inverse discrete Fourier transform, so that `ifft(&fft(x))` returns `x`.
+pub fn ifft<T>(input: &[Complex<T>]) -> Vec<Complex<T>>
+where
+  T: Float + FloatConst,
+{
+  let mut data = input.to_vec();
+  transform(&mut data, true);
+  let scale = T::from(data.len()).unwrap();
+  for z in data.iter_mut() {
+    *z = *z / scale;
+  }
+  data
+}
+
+/// Transforms `data` in place without normalization, choosing the algorithm by length.
+fn transform<T>(data: &mut [Complex<T>], inverse: bool)
+where
+  T: Float + FloatConst,
+{
+  let n = data.len();
+  if n <= 1 {
+    return;
+  }
+  if n.is_power_of_two() {
+    radix2(data, inverse);
+  } else {
+    bluestein(data, inverse);
+  }
+}
+
+/// Iterative radix-2 transform for power-of-two lengths.
+fn radix2<T>(data: &mut [Complex<T>], inverse: bool)
+where
+  T: Float + FloatConst,
+{
+  let n = data.len();
+  let bits = n.trailing_zeros();
+  for i in 0..n {
+    let j = i.reverse_bits() >> (usize::BITS - bits);
+    if i < j {
+      data.swap(i, j);
+    }
+  }
+
+  // Twiddle factors exp(-+2 pi i k / n), computed directly rather than by repeated
+  // multiplication so that rounding errors do not accumulate.
+  let sign = if inverse { T::one() } else { -T::one() };
+  let step = sign * T::TAU() / T::from(n).unwrap();
+  let twiddles: Vec<Complex<T>> = (0..n / 2)
+    .map(|k| Complex::from_polar(T::one(), step * T::from(k).unwrap()))
+    .collect();
+
+  let mut len = 2;
+  while len <= n {
+    let half = len / 2;
+    let stride = n / len;
+    for start in (0..n).step_by(len) {
+      for k in 0..half {
+        let a = data[start + k];
+        let b = data[start + k + half] * twiddles[k * stride];
+        data[start + k] = a + b;
+        data[start + k + half] = a - b;
+      }
+    }
+    len *= 2;
+  }
+}
+
+/// Bluestein's transform for arbitrary lengths, using `jk = (j^2 + k^2 - (k - j)^2) / 2`
+/// to express the transform as a convolution with a chirp.
+fn bluestein<T>(data: &mut [Complex<T>], inverse: bool)
+where
+  T: Float + FloatConst,
+{
+  let n = data.len();
+  let m = (2 * n - 1).next_power_of_two();
+  let sign = if inverse { T::one() } else { -T::one() };
+  // chirp[k] = exp(-+pi i k^2 / n); k^2 is reduced modulo 2n to keep the angle small.
+  let chirp: Vec<Complex<T>> = (0..n)
+    .map(|k| {
+      let k2 = (k as u128 * k as u128 % (2 * n as u128)) as usize;
+      Complex::from_polar(
+        T::one(),
+        sign * T::PI() * T::from(k2).unwrap() / T::from(n).unwrap(),
+      )
+    })
+    .collect();
+
+  let mut a = vec![Complex::new(T::zero(), T::zero()); m];
+  for (k, z) in data.iter().enumerate() {
+    a[k] = *z * chirp[k];
+  }
+  let mut b = vec![Complex::new(T::zero(), T::zero()); m];
+  b[0] = chirp[0].conj();
+  for k in 1..n {
+    b[k] = chirp[k].conj();
+    b[m - k] = chirp[k].conj();
+  }
+
+  radix2(&mut a, false);
+  radix2(&mut b, false);
+  for (x, y) in a.iter_mut().zip(b.iter()) {
+    *x = *x * *y;
+  }
+  radix2(&mut a, true);
+  let scale = T::from(m).unwrap();
+  for (k, z) in data.iter_mut().enumerate() {
+    *z = a[k] * chirp[k] / scale;
+  }
+}
@@ -0,0 +1,228 @@
+//! IIR and FIR filtering for `Vector<T>`, with Butterworth filter design, following
+//! scipy's `lfilter`, `filtfilt` and `butter`.
+//!
+//! A filter is given by its transfer function coefficients `b` (numerator) and `a`
+//! (denominator), so that `a[0] * y[n] = sum_k b[k] * x[n - k] - sum_{k>=1} a[k] * y[n - k]`.
+
+use crate::error::MathError;
+use crate::fft::Complex;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::{Float, FloatConst};
+
+/// The band a [`butter`] filter passes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterType {
+  /// Passes frequencies below the cutoff.
+  Lowpass,
+  /// Passes frequencies above the cutoff.
+  Highpass,
+}
+
+/// Trait providing linear filtering of `Vector<T>` signals.
+pub trait Filter<T> {
+  /// Filters the signal with a direct form II transposed structure, starting from rest
+  /// (all earlier inputs and outputs zero). The output has the input length.
+  /// Returns an error if `b` or `a` is empty or `a[0]` is zero.
+  fn lfilter(&self, b: &Vector<T>, a: &Vector<T>) -> Result<Vector<T>, MathError>;
+
+  /// Filters the signal forwards and then backwards, giving zero phase shift and the
+  /// squared magnitude response of the filter. To limit edge transients, the signal is
+  /// first extended at each end by `3 * max(b.len(), a.len())` samples, reflected through
+  /// the end value, and each pass starts from the steady state for its first sample.
+  /// Returns an error under the conditions of `lfilter`, or if the signal is not longer
+  /// than the extension.
+  fn filtfilt(&self, b: &Vector<T>, a: &Vector<T>) -> Result<Vector<T>, MathError>;
+}
+
+impl<T> Filter<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn lfilter(&self, b: &Vector<T>, a: &Vector<T>) -> Result<Vector<T>, MathError> {
+    let (b, a) = normalize(b, a)?;
+    let state = vec![T::zero(); b.len() - 1];
+    Ok(run_filter(&b, &a, self, state).into_vector())
+  }
+
+  fn filtfilt(&self, b: &Vector<T>, a: &Vector<T>) -> Result<Vector<T>, MathError> {
+    let (b, a) = normalize(b, a)?;
+    let pad = 3 * b.len();
+    let n = self.len();
+    if n <= pad {
+      return Err(MathError::InvalidArgument(format!(
+        "filtfilt requires a signal longer than {} samples, found {}",
+        pad, n
+      )));
+    }
+    let two = T::from(2.0).unwrap();
+    let mut extended = Vec::with_capacity(n + 2 * pad);
+    extended.extend((1..=pad).rev().map(|i| two * self[0] - self[i]));
+    extended.extend_from_slice(self);
+    extended.extend((1..=pad).map(|i| two * self[n - 1] - self[n - 1 - i]));
+
+    let zi = steady_state(&b, &a)?;
+    let scaled = |x0: T| zi.iter().map(|&z| z * x0).collect::<Vec<T>>();
+    let forward = run_filter(&b, &a, &extended, scaled(extended[0]));
+    let reversed: Vec<T> = forward.into_iter().rev().collect();
+    let backward = run_filter(&b, &a, &reversed, scaled(reversed[0]));
+    Ok(
+      backward[pad..pad + n]
+        .iter()
+        .rev()
+        .cloned()
+        .collect::<Vec<T>>()
+        .into_vector(),
+    )
+  }
+}
+
+/// Designs a digital Butterworth filter of the given `order`, returning its transfer
+/// function coefficients `(b, a)`.
+///
+/// `cutoff` is the -3 dB frequency as a fraction of the Nyquist frequency, so it must lie
+/// strictly between 0 and 1. The analog prototype is prewarped and mapped to the digital
+/// domain with the bilinear transform. High orders are numerically delicate in transfer
+/// function form, so orders above about 8 should be avoided.
+///
+/// Returns an error if `order` is zero or `cutoff` is out of range.
+pub fn butter<T>(
+  order: usize,
+  cutoff: T,
+  kind: FilterType,
+) -> Result<(Vector<T>, Vector<T>), MathError>
+where
+  T: Float + FloatConst,
+{
+  if order == 0 {
+    return Err(MathError::InvalidArgument(
+      "filter order must be at least 1".to_string(),
+    ));
+  }
+  if !(cutoff > T::zero() && cutoff < T::one()) {
+    return Err(MathError::InvalidArgument(
+      "cutoff must be strictly between 0 and 1".to_string(),
+    ));
+  }
+  let one = Complex::new(T::one(), T::zero());
+  let nt = T::from(order).unwrap();
+  // Bilinear transform with a sampling rate of 2, so the Nyquist frequency is 1.
+  let fs2 = T::from(4.0).unwrap();
+  let warped = fs2 * (T::PI() * cutoff / T::from(2.0).unwrap()).tan();
+
+  // Analog prototype poles on the left half of the unit circle, scaled to the cutoff.
+  let poles: Vec<Complex<T>> = (0..order)
+    .map(|k| {
+      let angle = T::PI() * T::from(2 * k + order + 1).unwrap() / (T::from(2.0).unwrap() * nt);
+      let p = Complex::from_polar(T::one(), angle);
+      match kind {
+        FilterType::Lowpass => p * warped,
+        FilterType::Highpass => Complex::new(warped, T::zero()) / p,
+      }
+    })
+    .collect();
+  let (zeros, gain): (Vec<Complex<T>>, T) = match kind {
+    FilterType::Lowpass => (Vec::new(), warped.powi(order as i32)),
+    FilterType::Highpass => (vec![Complex::new(T::zero(), T::zero()); order], T::one()),
+  };
+
+  let fs2c = Complex::new(fs2, T::zero());
+  let bilinear = |z: &Complex<T>| (fs2c + z) / (fs2c - z);
+  let mut digital_zeros: Vec<Complex<T>> = zeros.iter().map(bilinear).collect();
+  // Zeros at infinity in the analog filter map to the Nyquist frequency.
+  digital_zeros.resize(order, -one);
+  let digital_poles: Vec<Complex<T>> = poles.iter().map(bilinear).collect();
+  let num = zeros.iter().fold(one, |acc, z| acc * (fs2c - z));
+  let den = poles.iter().fold(one, |acc, p| acc * (fs2c - p));
+  let digital_gain = gain * (num / den).re;
+
+  let b = poly(&digital_zeros)
+    .into_iter()
+    .map(|c| c * digital_gain)
+    .collect::<Vec<T>>();
+  Ok((b.into_vector(), poly(&digital_poles).into_vector()))
+}
+
+/// Validates `(b, a)`, pads both to the same length and divides by `a[0]`.
+fn normalize<T: Float>(b: &[T], a: &[T]) -> Result<(Vec<T>, Vec<T>), MathError> {
+  if b.is_empty() || a.is_empty() {
+    return Err(MathError::InvalidArgument(
+      "filter coefficients must not be empty".to_string(),
+    ));
+  }
+  if a[0] == T::zero() {
+    return Err(MathError::InvalidArgument(
+      "the leading denominator coefficient must not be zero".to_string(),
+    ));
+  }
+  let len = b.len().max(a.len());
+  let scale = |c: &[T]| {
+    let mut out: Vec<T> = c.iter().map(|&v| v / a[0]).collect();
+    out.resize(len, T::zero());
+    out
+  };
+  Ok((scale(b), scale(a)))
+}
+
+/// Runs a normalized filter over `x` from the given delay-line state.
+fn run_filter<T: Float>(b: &[T], a: &[T], x: &[T], mut state: Vec<T>) -> Vec<T> {
+  let order = b.len() - 1;
+  x.iter()
+    .map(|&xi| {
+      let yi = if order == 0 {
+        b[0] * xi
+      } else {
+        b[0] * xi + state[0]
+      };
+      for k in 0..order {
+        let next = if k + 1 < order {
+          state[k + 1]
+        } else {
+          T::zero()
+        };
+        state[k] = next + b[k + 1] * xi - a[k + 1] * yi;
+      }
+      yi
+    })
+    .collect()
+}
+
+/// Delay-line state of a normalized filter whose input has been 1 forever, so that a
+/// step input produces no transient. Solves `(I - A^T) zi = b[1..] - a[1..] * b[0]`,
+/// where `A` is the companion matrix of `a`.
+fn steady_state<T: Float>(b: &[T], a: &[T]) -> Result<Vec<T>, MathError> {
+  let order = b.len() - 1;
+  if order == 0 {
+    return Ok(Vec::new());
+  }
+  let system = Matrix::from_fn(order, order, |i, j| {
+    // Companion matrix: first row -a[1..], ones on the subdiagonal.
+    let companion_ji = if j == 0 {
+      -a[i + 1]
+    } else if i == j - 1 {
+      T::one()
+    } else {
+      T::zero()
+    };
+    let identity = if i == j { T::one() } else { T::zero() };
+    identity - companion_ji
+  });
+  let rhs: Vec<T> = (1..=order).map(|k| b[k] - a[k] * b[0]).collect();
+  Ok(system.solve(&rhs.into_vector())?.to_vec())
+}
+
+/// Real coefficients of the monic polynomial with the given roots, highest power first.
+/// Complex roots must come in conjugate pairs.
+fn poly<T: Float>(roots: &[Complex<T>]) -> Vec<T> {
+  let mut coef = vec![Complex::new(T::one(), T::zero())];
+  for r in roots {
+    let mut next = coef.clone();
+    next.push(Complex::new(T::zero(), T::zero()));
+    for (k, c) in coef.iter().enumerate() {
+      next[k + 1] = next[k + 1] - c * r;
+    }
+    coef = next;
+  }
+  coef.into_iter().map(|c| c.re).collect()
+}
@@ -0,0 +1,350 @@
+//! Histograms and binning for `Vector<T>`, following numpy's `histogram` and `digitize`
+//! and pandas' `cut`.
+//!
+//! Bins are half-open intervals `[edges[i], edges[i + 1])`, except that the last bin also
+//! includes its right edge. NaN values are never counted.
+
+use crate::error::MathError;
+use crate::statistics::Statistics;
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::{Float, FloatConst};
+
+/// Most bins a width or rule may give. More would take hundreds of megabytes, and
+/// usually means the width is far too small for the range of the data.
+pub const MAX_BINS: usize = 10_000_000;
+
+/// Rule for choosing the bin width from the data, as in numpy's `histogram_bin_edges`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinRule {
+  /// `log2(n) + 1` bins. Suited to roughly normal data and small samples.
+  Sturges,
+  /// Width `(24 * sqrt(pi) / n)^(1/3) * stddev`, optimal for normal data.
+  Scott,
+  /// Width `2 * iqr / n^(1/3)`, robust to outliers.
+  FreedmanDiaconis,
+  /// `sqrt(n)` bins.
+  Sqrt,
+}
+
+/// How [`Histogram::new`] chooses its bins.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bins<T> {
+  /// The given number of equal-width bins spanning the data.
+  Count(usize),
+  /// Bins of the given width, starting at the data minimum.
+  Width(T),
+  /// Explicit, strictly increasing bin edges.
+  Edges(Vector<T>),
+  /// Equal-width bins with the width chosen by a rule.
+  Rule(BinRule),
+}
+
+/// A histogram: bin edges and the number of values in each bin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram<T> {
+  edges: Vector<T>,
+  counts: Vec<usize>,
+}
+
+impl<T> Histogram<T>
+where
+  T: Float + FloatConst,
+{
+  /// Builds a histogram of `data`. Values outside explicit edges are not counted.
+  ///
+  /// Data-driven bins span the non-NaN values; when they are all equal, the range is
+  /// widened to half a unit either side, as numpy does. Returns an error if the bin
+  /// count is zero, the width is not positive, a width or rule gives more than
+  /// [`MAX_BINS`] bins, the edges are invalid, or bins must be derived from data with no
+  /// non-NaN values.
+  pub fn new(data: &Vector<T>, bins: Bins<T>) -> Result<Self, MathError> {
+    let edges = match bins {
+      Bins::Edges(edges) => edges,
+      Bins::Count(count) => {
+        if count == 0 {
+          return Err(MathError::InvalidArgument(
+            "a histogram needs at least one bin".to_string(),
+          ));
+        }
+        let (lo, hi) = data_range(data)?;
+        Vector::linspace(lo, hi, count + 1)
+      }
+      Bins::Width(width) => {
+        if width.is_nan() || width <= T::zero() {
+          return Err(MathError::InvalidArgument(
+            "bin width must be positive".to_string(),
+          ));
+        }
+        let (lo, hi) = data_range(data)?;
+        let count = checked_bin_count((hi - lo) / width)?;
+        (0..=count)
+          .map(|i| lo + width * T::from(i).unwrap())
+          .collect::<Vec<T>>()
+          .into_vector()
+      }
+      Bins::Rule(rule) => {
+        let (lo, hi) = data_range(data)?;
+        Vector::linspace(lo, hi, rule_bin_count(data, rule, hi - lo)? + 1)
+      }
+    };
+    let mut histogram = Histogram::from_edges(edges)?;
+    histogram.extend(data);
+    Ok(histogram)
+  }
+
+  /// Creates an empty histogram with the given edges, to be filled with `add` or `extend`.
+  /// Returns an error unless there are at least two finite, strictly increasing edges.
+  pub fn from_edges(edges: Vector<T>) -> Result<Self, MathError> {
+    if edges.len() < 2
+      || edges.iter().any(|e| !e.is_finite())
+      || edges.windows(2).any(|w| w[0] >= w[1])
+    {
+      return Err(MathError::InvalidArgument(
+        "histogram edges must be at least two finite, strictly increasing values".to_string(),
+      ));
+    }
+    let bins = edges.len() - 1;
+    Ok(Histogram {
+      edges,
+      counts: vec![0; bins],
+    })
+  }
+
+  /// Counts a value. NaN and values outside the edges are ignored.
+  pub fn add(&mut self, x: T) {
+    if let Some(bin) = self.bin_of(x) {
+      self.counts[bin] += 1;
+    }
+  }
+
+  /// Counts every value of `data`, as `add` does.
+  pub fn extend(&mut self, data: &[T]) {
+    for &x in data {
+      self.add(x);
+    }
+  }
+
+  /// Adds the counts of `other`, which must have the same edges, so that histograms of
+  /// separate chunks of data can be combined.
+  pub fn merge(&mut self, other: &Histogram<T>) -> Result<(), MathError> {
+    if self.edges != other.edges {
+      return Err(MathError::InvalidArgument(
+        "histograms with different edges cannot be merged".to_string(),
+      ));
+    }
+    for (a, b) in self.counts.iter_mut().zip(&other.counts) {
+      *a += b;
+    }
+    Ok(())
+  }
+
+  /// Returns the bin index of `x`, or `None` for NaN and values outside the edges.
+  pub fn bin_of(&self, x: T) -> Option<usize> {
+    let last = self.edges.len() - 1;
+    if x.is_nan() || x < self.edges[0] || x > self.edges[last] {
+      return None;
+    }
+    Some((self.edges.partition_point(|&e| e <= x) - 1).min(last - 1))
+  }
+
+  /// Returns the bin edges, one more than the number of bins.
+  pub fn edges(&self) -> &Vector<T> {
+    &self.edges
+  }
+
+  /// Returns the number of values in each bin.
+  pub fn counts(&self) -> &[usize] {
+    &self.counts
+  }
+
+  /// Returns the number of bins.
+  pub fn len(&self) -> usize {
+    self.counts.len()
+  }
+
+  /// Returns `true` if no value has been counted.
+  pub fn is_empty(&self) -> bool {
+    self.total() == 0
+  }
+
+  /// Returns the total number of values counted.
+  pub fn total(&self) -> usize {
+    self.counts.iter().sum()
+  }
+
+  /// Returns the midpoint of each bin.
+  pub fn centers(&self) -> Vector<T> {
+    let half = T::from(0.5).unwrap();
+    self
+      .edges
+      .windows(2)
+      .map(|w| (w[0] + w[1]) * half)
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// Returns the probability density in each bin: the count divided by the total and the
+  /// bin width, so that the density integrates to one. All zeros for an empty histogram.
+  pub fn density(&self) -> Vector<T> {
+    let total = T::from(self.total()).unwrap();
+    self
+      .counts
+      .iter()
+      .zip(self.edges.windows(2))
+      .map(|(&c, w)| {
+        if total == T::zero() {
+          T::zero()
+        } else {
+          T::from(c).unwrap() / (total * (w[1] - w[0]))
+        }
+      })
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// Returns the running total of the counts, so the last entry is `total()`.
+  pub fn cumulative(&self) -> Vec<usize> {
+    self
+      .counts
+      .iter()
+      .scan(0, |acc, &c| {
+        *acc += c;
+        Some(*acc)
+      })
+      .collect()
+  }
+}
+
+/// Trait providing binning of `Vector<T>` values.
+pub trait Binning<T> {
+  /// Builds a histogram of the values. See [`Histogram::new`].
+  fn histogram(&self, bins: Bins<T>) -> Result<Histogram<T>, MathError>;
+
+  /// Returns, for each value, the index `i` of the edges it falls between, as numpy's
+  /// `digitize` does: `edges[i - 1] <= x < edges[i]`, or `edges[i - 1] < x <= edges[i]`
+  /// with `right`. Values below the first edge give 0, values above the last give
+  /// `edges.len()`, and NaN gives `edges.len()`.
+  /// Returns an error if the edges are not increasing.
+  fn digitize(&self, edges: &Vector<T>, right: bool) -> Result<Vec<usize>, MathError>;
+
+  /// Returns, for each value, the index of the bin between consecutive edges it falls
+  /// in, as pandas' `cut` does. Bins are `(edges[i], edges[i + 1]]`, or
+  /// `[edges[i], edges[i + 1])` when `right` is `false`. NaN and values outside every
+  /// bin give `None`.
+  /// Returns an error unless there are at least two strictly increasing edges.
+  fn cut(&self, edges: &Vector<T>, right: bool) -> Result<Vec<Option<usize>>, MathError>;
+}
+
+impl<T> Binning<T> for Vector<T>
+where
+  T: Float + FloatConst,
+{
+  fn histogram(&self, bins: Bins<T>) -> Result<Histogram<T>, MathError> {
+    Histogram::new(self, bins)
+  }
+
+  fn digitize(&self, edges: &Vector<T>, right: bool) -> Result<Vec<usize>, MathError> {
+    if edges.iter().any(|e| e.is_nan()) || edges.windows(2).any(|w| w[0] > w[1]) {
+      return Err(MathError::InvalidArgument(
+        "edges must be increasing".to_string(),
+      ));
+    }
+    Ok(
+      self
+        .iter()
+        .map(|&x| {
+          if x.is_nan() {
+            edges.len()
+          } else if right {
+            edges.partition_point(|&e| e < x)
+          } else {
+            edges.partition_point(|&e| e <= x)
+          }
+        })
+        .collect(),
+    )
+  }
+
+  fn cut(&self, edges: &Vector<T>, right: bool) -> Result<Vec<Option<usize>>, MathError> {
+    if edges.len() < 2 || edges.iter().any(|e| e.is_nan()) || edges.windows(2).any(|w| w[0] >= w[1])
+    {
+      return Err(MathError::InvalidArgument(
+        "cut requires at least two strictly increasing edges".to_string(),
+      ));
+    }
+    let bins = edges.len() - 1;
+    Ok(
+      self
+        .digitize(edges, right)?
+        .into_iter()
+        .map(|i| {
+          if i == 0 || i > bins {
+            None
+          } else {
+            Some(i - 1)
+          }
+        })
+        .collect(),
+    )
+  }
+}
+
+/// Range of the non-NaN values, widened when they are all equal.
+fn data_range<T: Float>(data: &Vector<T>) -> Result<(T, T), MathError> {
+  let (lo, hi) = match (data.min(), data.max()) {
+    (Some(lo), Some(hi)) => (lo, hi),
+    _ => {
+      return Err(MathError::InvalidArgument(
+        "cannot choose bins for data with no non-NaN values".to_string(),
+      ))
+    }
+  };
+  if lo == hi {
+    let half = T::from(0.5).unwrap();
+    Ok((lo - half, hi + half))
+  } else {
+    Ok((lo, hi))
+  }
+}
+
+/// Number of equal-width bins a rule gives for data spanning `range`. Falls back to a
+/// single bin when the rule's width is zero.
+fn rule_bin_count<T: Float + FloatConst>(
+  data: &Vector<T>,
+  rule: BinRule,
+  range: T,
+) -> Result<usize, MathError> {
+  let n = T::from(data.iter().filter(|x| !x.is_nan()).count()).unwrap();
+  let third = T::from(1.0 / 3.0).unwrap();
+  let count = match rule {
+    BinRule::Sturges => n.log2() + T::one(),
+    BinRule::Sqrt => n.sqrt(),
+    BinRule::Scott => {
+      let width = (T::from(24.0).unwrap() * T::PI().sqrt() / n).powf(third)
+        * data.stddev().unwrap_or(T::zero());
+      range / width
+    }
+    BinRule::FreedmanDiaconis => {
+      let width = T::from(2.0).unwrap() * data.iqr().unwrap_or(T::zero()) / n.powf(third);
+      range / width
+    }
+  };
+  if count.is_finite() {
+    checked_bin_count(count)
+  } else {
+    Ok(1)
+  }
+}
+
+/// Rounds a fractional bin count up to at least one bin. Returns an error if that is
+/// more than [`MAX_BINS`].
+fn checked_bin_count<T: Float>(count: T) -> Result<usize, MathError> {
+  match count.ceil().to_usize() {
+    Some(count) if count <= MAX_BINS => Ok(count.max(1)),
+    _ => Err(MathError::InvalidArgument(format!(
+      "the bin width gives more than {} bins",
+      MAX_BINS
+    ))),
+  }
+}
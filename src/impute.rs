@@ -0,0 +1,166 @@
+//! Missing value imputation and interpolation for `Vector<T>`.
+//!
+//! NaN is treated as the missing-value marker throughout the crate. The methods
+//! here return a new vector with some or all NaN values replaced.
+
+use crate::interp::spline_second_derivatives;
+use crate::statistics::Statistics;
+use crate::vector::Vector;
+use num_traits::Float;
+
+/// Interpolation method used by [`Impute::interpolate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+  /// Straight line between the two surrounding valid values.
+  Linear,
+  /// Value of the closest valid neighbour. Ties go to the earlier neighbour.
+  Nearest,
+  /// Natural cubic spline through all valid values.
+  /// Falls back to linear interpolation when fewer than three values are valid.
+  Spline,
+}
+
+/// Trait providing missing value imputation methods for `Vector<T>`.
+pub trait Impute<T> {
+  /// Replaces every NaN with `value`.
+  fn fill_nan(&self, value: T) -> Vector<T>;
+
+  /// Replaces every NaN with the mean of the non-NaN values.
+  /// The vector is returned unchanged if it contains no valid values.
+  fn fill_mean(&self) -> Vector<T>;
+
+  /// Replaces every NaN with the median of the non-NaN values.
+  /// The vector is returned unchanged if it contains no valid values.
+  fn fill_median(&self) -> Vector<T>;
+
+  /// Propagates the last valid value forward over NaN values.
+  /// At most `limit` consecutive NaN values are filled when a limit is given.
+  /// Leading NaN values have nothing to propagate and are left as NaN.
+  fn ffill(&self, limit: Option<usize>) -> Vector<T>;
+
+  /// Propagates the next valid value backward over NaN values.
+  /// At most `limit` consecutive NaN values are filled when a limit is given.
+  /// Trailing NaN values have nothing to propagate and are left as NaN.
+  fn bfill(&self, limit: Option<usize>) -> Vector<T>;
+
+  /// Fills NaN gaps using the element index as the x coordinate.
+  /// Only gaps with a valid value on both sides are filled;
+  /// leading and trailing NaN values are left as NaN.
+  fn interpolate(&self, method: Interpolation) -> Vector<T>;
+}
+
+impl<T> Impute<T> for Vector<T>
+where
+  T: Float + Copy + PartialOrd,
+{
+  fn fill_nan(&self, value: T) -> Vector<T> {
+    let data = self
+      .iter()
+      .map(|&x| if x.is_nan() { value } else { x })
+      .collect();
+    Vector::new(data)
+  }
+
+  fn fill_mean(&self) -> Vector<T> {
+    match self.mean() {
+      Some(mean) => self.fill_nan(mean),
+      None => self.clone(),
+    }
+  }
+
+  fn fill_median(&self) -> Vector<T> {
+    match self.median() {
+      Some(median) => self.fill_nan(median),
+      None => self.clone(),
+    }
+  }
+
+  fn ffill(&self, limit: Option<usize>) -> Vector<T> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut result = self.clone();
+    let mut last = None;
+    let mut run = 0;
+    for x in result.iter_mut() {
+      if x.is_nan() {
+        run += 1;
+        if let Some(value) = last {
+          if run <= limit {
+            *x = value;
+          }
+        }
+      } else {
+        last = Some(*x);
+        run = 0;
+      }
+    }
+    result
+  }
+
+  fn bfill(&self, limit: Option<usize>) -> Vector<T> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut result = self.clone();
+    let mut next = None;
+    let mut run = 0;
+    for x in result.iter_mut().rev() {
+      if x.is_nan() {
+        run += 1;
+        if let Some(value) = next {
+          if run <= limit {
+            *x = value;
+          }
+        }
+      } else {
+        next = Some(*x);
+        run = 0;
+      }
+    }
+    result
+  }
+
+  fn interpolate(&self, method: Interpolation) -> Vector<T> {
+    let known: Vec<usize> = (0..self.len()).filter(|&i| !self[i].is_nan()).collect();
+    let mut result = self.clone();
+    if known.len() < 2 {
+      return result;
+    }
+
+    let second_derivs = match method {
+      Interpolation::Spline if known.len() >= 3 => {
+        let xs: Vec<T> = known.iter().map(|&i| T::from(i).unwrap()).collect();
+        let ys: Vec<T> = known.iter().map(|&i| self[i]).collect();
+        Some(spline_second_derivatives(&xs, &ys))
+      }
+      _ => None,
+    };
+
+    for (seg, pair) in known.windows(2).enumerate() {
+      let (i0, i1) = (pair[0], pair[1]);
+      if i1 - i0 < 2 {
+        continue;
+      }
+      let (y0, y1) = (self[i0], self[i1]);
+      let h = T::from(i1 - i0).unwrap();
+      for (i, slot) in result.iter_mut().enumerate().take(i1).skip(i0 + 1) {
+        let t = T::from(i - i0).unwrap() / h;
+        *slot = match (method, &second_derivs) {
+          (Interpolation::Nearest, _) => {
+            if i - i0 <= i1 - i {
+              y0
+            } else {
+              y1
+            }
+          }
+          (Interpolation::Spline, Some(m)) => {
+            let a = T::one() - t;
+            let six = T::from(6.0).unwrap();
+            a * y0
+              + t * y1
+              + ((a * a * a - a) * m[seg] + (t * t * t - t) * m[seg + 1]) * h * h / six
+          }
+          _ => y0 + (y1 - y0) * t,
+        };
+      }
+    }
+    result
+  }
+}
@@ -0,0 +1,288 @@
+//! One-dimensional interpolation of samples `(x, y)` on irregular grids, and resampling.
+//!
+//! Sample pairs where either value is NaN are dropped, and the rest are sorted by `x`.
+//! Query points that are NaN give NaN.
+
+use crate::error::{check_same_len, MathError};
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::Float;
+
+/// Interpolation method used by [`interp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpMethod {
+  /// Straight line between the two surrounding samples.
+  Linear,
+  /// Value of the closest sample. Ties go to the earlier sample.
+  Nearest,
+  /// Value of the closest sample at or before the query point.
+  Previous,
+  /// Value of the closest sample at or after the query point.
+  Next,
+  /// Piecewise cubic Hermite interpolation with Fritsch-Carlson slopes (PCHIP), which
+  /// preserves monotonicity and does not overshoot the data.
+  Pchip,
+  /// Natural cubic spline, with zero curvature at both ends.
+  CubicSpline,
+}
+
+/// What [`interp`] returns for query points outside the range of the samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Extrapolate<T> {
+  /// Return NaN.
+  Nan,
+  /// Return the value at the nearest end sample.
+  Clamp,
+  /// Return the given value.
+  Fill(T),
+  /// Continue the end piece of the interpolant: the end line for `Linear`, the end cubic
+  /// for `Pchip` and `CubicSpline`, and the end value for the step methods.
+  Extend,
+  /// Return an error.
+  Error,
+}
+
+/// Interpolates the samples `(x, y)` at each point of `query`.
+///
+/// Returns an error if `x` and `y` differ in length, no complete sample remains, two
+/// samples share an `x` value, or a query point is out of range under `Extrapolate::Error`.
+pub fn interp<T>(
+  x: &Vector<T>,
+  y: &Vector<T>,
+  query: &Vector<T>,
+  method: InterpMethod,
+  extrapolate: Extrapolate<T>,
+) -> Result<Vector<T>, MathError>
+where
+  T: Float + Copy,
+{
+  check_same_len(x, y)?;
+  let mut pairs: Vec<(T, T)> = x
+    .iter()
+    .zip(y.iter())
+    .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+    .map(|(&a, &b)| (a, b))
+    .collect();
+  if pairs.is_empty() {
+    return Err(MathError::InvalidArgument(
+      "interpolation requires at least one sample".to_string(),
+    ));
+  }
+  pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+  if pairs.windows(2).any(|w| w[0].0 == w[1].0) {
+    return Err(MathError::InvalidArgument(
+      "sample x values must be distinct".to_string(),
+    ));
+  }
+  let (xs, ys): (Vec<T>, Vec<T>) = pairs.into_iter().unzip();
+  let interpolant = Interpolant::new(xs, ys, method);
+
+  let (lo, hi) = (interpolant.xs[0], interpolant.xs[interpolant.xs.len() - 1]);
+  let mut result = Vec::with_capacity(query.len());
+  for &q in query.iter() {
+    let value = if q.is_nan() {
+      T::nan()
+    } else if q >= lo && q <= hi {
+      interpolant.eval(q)
+    } else {
+      match extrapolate {
+        Extrapolate::Nan => T::nan(),
+        Extrapolate::Clamp => interpolant.eval(q.max(lo).min(hi)),
+        Extrapolate::Fill(v) => v,
+        Extrapolate::Extend => interpolant.eval(q),
+        Extrapolate::Error => {
+          return Err(MathError::InvalidArgument(format!(
+            "query point {} is outside the sample range [{}, {}]",
+            q.to_f64().unwrap(),
+            lo.to_f64().unwrap(),
+            hi.to_f64().unwrap()
+          )));
+        }
+      }
+    };
+    result.push(value);
+  }
+  Ok(result.into_vector())
+}
+
+/// Trait providing resampling of `Vector<T>` treated as evenly spaced samples.
+pub trait Resample<T> {
+  /// Resamples to `n` evenly spaced points spanning the same range, interpolating with
+  /// `method`. The first and last values are kept, and NaN values are interpolated over.
+  /// Returns an error if the vector has no non-NaN values.
+  fn resample(&self, n: usize, method: InterpMethod) -> Result<Vector<T>, MathError>;
+}
+
+impl<T> Resample<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn resample(&self, n: usize, method: InterpMethod) -> Result<Vector<T>, MathError> {
+    let last = T::from(self.len().saturating_sub(1)).unwrap();
+    let x = Vector::linspace(T::zero(), last, self.len());
+    interp(
+      &x,
+      self,
+      &Vector::linspace(T::zero(), last, n),
+      method,
+      Extrapolate::Clamp,
+    )
+  }
+}
+
+/// Sorted, distinct samples together with the per-method data needed to evaluate them.
+struct Interpolant<T> {
+  xs: Vec<T>,
+  ys: Vec<T>,
+  method: InterpMethod,
+  /// First derivatives for `Pchip`, second derivatives for `CubicSpline`.
+  derivs: Vec<T>,
+}
+
+impl<T: Float> Interpolant<T> {
+  fn new(xs: Vec<T>, ys: Vec<T>, method: InterpMethod) -> Self {
+    let derivs = match method {
+      InterpMethod::Pchip => pchip_slopes(&xs, &ys),
+      InterpMethod::CubicSpline => spline_second_derivatives(&xs, &ys),
+      _ => Vec::new(),
+    };
+    Interpolant {
+      xs,
+      ys,
+      method,
+      derivs,
+    }
+  }
+
+  /// Evaluates at `q`, using the end pieces for points outside the samples.
+  fn eval(&self, q: T) -> T {
+    let n = self.xs.len();
+    if n == 1 {
+      return self.ys[0];
+    }
+    // Index of the interval [xs[i], xs[i + 1]] containing q, clamped to the end intervals.
+    let i = self.xs.partition_point(|&v| v <= q).clamp(1, n - 1) - 1;
+    let (x0, x1, y0, y1) = (self.xs[i], self.xs[i + 1], self.ys[i], self.ys[i + 1]);
+    let h = x1 - x0;
+    let t = (q - x0) / h;
+    match self.method {
+      InterpMethod::Linear => y0 + (y1 - y0) * t,
+      InterpMethod::Nearest => {
+        if q - x0 <= x1 - q {
+          y0
+        } else {
+          y1
+        }
+      }
+      InterpMethod::Previous => {
+        if q >= x1 {
+          y1
+        } else {
+          y0
+        }
+      }
+      InterpMethod::Next => {
+        if q <= x0 {
+          y0
+        } else {
+          y1
+        }
+      }
+      InterpMethod::Pchip => {
+        // Cubic Hermite basis functions.
+        let (t2, t3) = (t * t, t * t * t);
+        let two = T::from(2.0).unwrap();
+        let three = T::from(3.0).unwrap();
+        let h00 = two * t3 - three * t2 + T::one();
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+        h00 * y0 + h10 * h * self.derivs[i] + h01 * y1 + h11 * h * self.derivs[i + 1]
+      }
+      InterpMethod::CubicSpline => {
+        let a = T::one() - t;
+        let six = T::from(6.0).unwrap();
+        a * y0
+          + t * y1
+          + ((a * a * a - a) * self.derivs[i] + (t * t * t - t) * self.derivs[i + 1]) * h * h / six
+      }
+    }
+  }
+}
+
+/// Fritsch-Carlson derivative estimates for PCHIP, as computed by scipy's
+/// `PchipInterpolator`: weighted harmonic means of the neighbouring secant slopes at
+/// interior points, zero at local extrema, and a shape-preserving three-point formula
+/// at the ends.
+fn pchip_slopes<T: Float>(xs: &[T], ys: &[T]) -> Vec<T> {
+  let n = xs.len();
+  if n < 2 {
+    return vec![T::zero(); n];
+  }
+  let h: Vec<T> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+  let delta: Vec<T> = (0..n - 1).map(|k| (ys[k + 1] - ys[k]) / h[k]).collect();
+  if n == 2 {
+    return vec![delta[0]; 2];
+  }
+  let two = T::from(2.0).unwrap();
+  let three = T::from(3.0).unwrap();
+  let mut d = vec![T::zero(); n];
+  for k in 1..n - 1 {
+    let (a, b) = (delta[k - 1], delta[k]);
+    if a == T::zero() || b == T::zero() || a.signum() != b.signum() {
+      continue;
+    }
+    let w1 = two * h[k] + h[k - 1];
+    let w2 = h[k] + two * h[k - 1];
+    d[k] = (w1 + w2) / (w1 / a + w2 / b);
+  }
+  // Sign that maps zero to zero, unlike `Float::signum`.
+  let sign = |v: T| {
+    if v == T::zero() {
+      T::zero()
+    } else {
+      v.signum()
+    }
+  };
+  let edge = |h0: T, h1: T, m0: T, m1: T| {
+    let d = ((two * h0 + h1) * m0 - h0 * m1) / (h0 + h1);
+    if sign(d) != sign(m0) {
+      T::zero()
+    } else if sign(m0) != sign(m1) && d.abs() > three * m0.abs() {
+      three * m0
+    } else {
+      d
+    }
+  };
+  d[0] = edge(h[0], h[1], delta[0], delta[1]);
+  d[n - 1] = edge(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+  d
+}
+
+/// Solves for the second derivatives of a natural cubic spline through `(xs[k], ys[k])`,
+/// where `xs` is strictly increasing. The end points have zero curvature.
+pub(crate) fn spline_second_derivatives<T: Float>(xs: &[T], ys: &[T]) -> Vec<T> {
+  let n = xs.len();
+  let two = T::from(2.0).unwrap();
+  let six = T::from(6.0).unwrap();
+  let mut m = vec![T::zero(); n];
+  if n < 3 {
+    return m;
+  }
+  let mut c_prime = vec![T::zero(); n];
+  let mut d_prime = vec![T::zero(); n];
+
+  // Thomas algorithm over the interior knots.
+  for k in 1..n - 1 {
+    let h0 = xs[k] - xs[k - 1];
+    let h1 = xs[k + 1] - xs[k];
+    let rhs = six * ((ys[k + 1] - ys[k]) / h1 - (ys[k] - ys[k - 1]) / h0);
+    let denom = two * (h0 + h1) - h0 * c_prime[k - 1];
+    c_prime[k] = h1 / denom;
+    d_prime[k] = (rhs - h0 * d_prime[k - 1]) / denom;
+  }
+  for k in (1..n - 1).rev() {
+    m[k] = d_prime[k] - c_prime[k] * m[k + 1];
+  }
+  m
+}
@@ -0,0 +1,324 @@
+//! Kernel density estimation for `Vector<T>`.
+//!
+//! Kernels are scaled so that the bandwidth is the standard deviation of the kernel, as in
+//! R's `density`, so a bandwidth rule gives comparable smoothing for every kernel.
+//! NaN values in the data are dropped.
+
+use crate::convolution::{Convolution, ConvolveMethod, ConvolveMode};
+use crate::error::MathError;
+use crate::special::normal_cdf;
+use crate::statistics::Statistics;
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::{Float, FloatConst};
+
+/// Above this many samples, [`Kde::grid_between`] bins the data and convolves it with the
+/// kernel instead of summing every kernel at every grid point.
+const DIRECT_MAX_SAMPLES: usize = 500;
+
+/// Number of bandwidths the default grid extends beyond the data on each side.
+const GRID_CUT: f64 = 3.0;
+
+/// Smoothing kernel of a [`Kde`]. All but `Gaussian` have bounded support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kernel {
+  /// The standard normal density.
+  Gaussian,
+  /// `3/4 (1 - u^2)`, optimal in mean integrated squared error.
+  Epanechnikov,
+  /// `1/2` on `[-1, 1]`.
+  Uniform,
+  /// `1 - |u|`.
+  Triangular,
+  /// `15/16 (1 - u^2)^2`, also called quartic.
+  Biweight,
+  /// `(1 + cos(pi u)) / 2`.
+  Cosine,
+}
+
+/// How a [`Kde`] chooses its bandwidth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bandwidth<T> {
+  /// Silverman's rule of thumb, `0.9 min(stddev, iqr / 1.34) n^(-1/5)`.
+  Silverman,
+  /// Scott's rule, `1.06 min(stddev, iqr / 1.34) n^(-1/5)`.
+  Scott,
+  /// The given bandwidth.
+  Fixed(T),
+}
+
+impl Kernel {
+  /// Variance of the kernel in its standard form.
+  fn variance<T: Float + FloatConst>(self) -> T {
+    let c = |x: f64| T::from(x).unwrap();
+    match self {
+      Kernel::Gaussian => T::one(),
+      Kernel::Epanechnikov => c(0.2),
+      Kernel::Uniform => c(1.0 / 3.0),
+      Kernel::Triangular => c(1.0 / 6.0),
+      Kernel::Biweight => c(1.0 / 7.0),
+      Kernel::Cosine => c(1.0 / 3.0) - c(2.0) / (T::PI() * T::PI()),
+    }
+  }
+
+  /// Half-width of the support in standard form, infinite for `Gaussian`.
+  fn support<T: Float>(self) -> T {
+    match self {
+      Kernel::Gaussian => T::infinity(),
+      _ => T::one(),
+    }
+  }
+
+  /// Density of the kernel in standard form.
+  fn pdf<T: Float + FloatConst>(self, u: T) -> T {
+    let c = |x: f64| T::from(x).unwrap();
+    if u.abs() > self.support() {
+      return T::zero();
+    }
+    match self {
+      Kernel::Gaussian => (-u * u / c(2.0)).exp() / (c(2.0) * T::PI()).sqrt(),
+      Kernel::Epanechnikov => c(0.75) * (T::one() - u * u),
+      Kernel::Uniform => c(0.5),
+      Kernel::Triangular => T::one() - u.abs(),
+      Kernel::Biweight => {
+        let v = T::one() - u * u;
+        c(15.0 / 16.0) * v * v
+      }
+      Kernel::Cosine => (T::one() + (T::PI() * u).cos()) / c(2.0),
+    }
+  }
+
+  /// Distribution function of the kernel in standard form.
+  fn cdf<T: Float + FloatConst>(self, u: T) -> T {
+    let c = |x: f64| T::from(x).unwrap();
+    if u < -self.support::<T>() {
+      return T::zero();
+    }
+    if u > self.support() {
+      return T::one();
+    }
+    match self {
+      Kernel::Gaussian => normal_cdf(u),
+      Kernel::Epanechnikov => (c(2.0) + c(3.0) * u - u * u * u) / c(4.0),
+      Kernel::Uniform => (u + T::one()) / c(2.0),
+      Kernel::Triangular => {
+        if u < T::zero() {
+          (T::one() + u) * (T::one() + u) / c(2.0)
+        } else {
+          T::one() - (T::one() - u) * (T::one() - u) / c(2.0)
+        }
+      }
+      Kernel::Biweight => {
+        let u3 = u * u * u;
+        c(0.5) + c(15.0 / 16.0) * (u - c(2.0 / 3.0) * u3 + u3 * u * u / c(5.0))
+      }
+      Kernel::Cosine => (T::one() + u + (T::PI() * u).sin() / T::PI()) / c(2.0),
+    }
+  }
+}
+
+/// A kernel density estimate built from the non-NaN values of a sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kde<T> {
+  /// Sorted sample values.
+  data: Vec<T>,
+  kernel: Kernel,
+  bandwidth: T,
+  /// Factor from the bandwidth to the half-width of the standard kernel.
+  scale: T,
+}
+
+impl<T> Kde<T>
+where
+  T: Float + FloatConst,
+{
+  /// Builds a density estimate of `data` with the given kernel and bandwidth.
+  ///
+  /// When `iqr / 1.34` or the standard deviation is zero, the rules fall back to the
+  /// other, as R's `bw.nrd0` does. Returns an error if there are no non-NaN values, a
+  /// rule is used with fewer than two or with all values equal, or a fixed bandwidth is
+  /// not positive and finite.
+  pub fn new(data: &Vector<T>, kernel: Kernel, bandwidth: Bandwidth<T>) -> Result<Self, MathError> {
+    let mut values: Vec<T> = data.iter().cloned().filter(|x| !x.is_nan()).collect();
+    if values.is_empty() {
+      return Err(MathError::InvalidArgument(
+        "kernel density estimation requires at least one non-NaN value".to_string(),
+      ));
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let bandwidth = match bandwidth {
+      Bandwidth::Fixed(h) => h,
+      Bandwidth::Silverman => rule_of_thumb(data, values.len(), T::from(0.9).unwrap())?,
+      Bandwidth::Scott => rule_of_thumb(data, values.len(), T::from(1.06).unwrap())?,
+    };
+    if !bandwidth.is_finite() || bandwidth <= T::zero() {
+      return Err(MathError::InvalidArgument(
+        "bandwidth must be positive and finite".to_string(),
+      ));
+    }
+    Ok(Kde {
+      data: values,
+      kernel,
+      bandwidth,
+      scale: kernel.variance::<T>().sqrt().recip(),
+    })
+  }
+
+  /// Returns the kernel.
+  pub fn kernel(&self) -> Kernel {
+    self.kernel
+  }
+
+  /// Returns the bandwidth, the standard deviation of the scaled kernel.
+  pub fn bandwidth(&self) -> T {
+    self.bandwidth
+  }
+
+  /// Estimates the density at `x`. NaN gives NaN.
+  pub fn pdf(&self, x: T) -> T {
+    if x.is_nan() {
+      return x;
+    }
+    let h = self.bandwidth * self.scale;
+    let sum = self
+      .near(x)
+      .iter()
+      .fold(T::zero(), |acc, &xi| acc + self.kernel.pdf((x - xi) / h));
+    sum / (T::from(self.data.len()).unwrap() * h)
+  }
+
+  /// Estimates the density at each point of `points`.
+  pub fn pdf_many(&self, points: &Vector<T>) -> Vector<T> {
+    points
+      .iter()
+      .map(|&x| self.pdf(x))
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// Estimates the cumulative probability at `x`. NaN gives NaN.
+  pub fn cdf(&self, x: T) -> T {
+    if x.is_nan() {
+      return x;
+    }
+    let h = self.bandwidth * self.scale;
+    let reach = h * self.kernel.support::<T>();
+    // Samples entirely below x contribute one each.
+    let below = self.data.partition_point(|&xi| xi < x - reach);
+    let sum = self
+      .near(x)
+      .iter()
+      .fold(T::from(below).unwrap(), |acc, &xi| {
+        acc + self.kernel.cdf((x - xi) / h)
+      });
+    sum / T::from(self.data.len()).unwrap()
+  }
+
+  /// Estimates the cumulative probability at each point of `points`.
+  pub fn cdf_many(&self, points: &Vector<T>) -> Vector<T> {
+    points
+      .iter()
+      .map(|&x| self.cdf(x))
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// Estimates the density on `num` evenly spaced points from three bandwidths below the
+  /// smallest value to three above the largest, returning the points and the densities.
+  pub fn grid(&self, num: usize) -> (Vector<T>, Vector<T>) {
+    let cut = self.bandwidth * T::from(GRID_CUT).unwrap();
+    let lo = self.data[0] - cut;
+    let hi = self.data[self.data.len() - 1] + cut;
+    (
+      Vector::linspace(lo, hi, num),
+      self.grid_between(lo, hi, num),
+    )
+  }
+
+  /// Estimates the density on `num` evenly spaced points from `lo` to `hi`.
+  ///
+  /// For large samples lying within the grid, the data are linearly binned onto the grid
+  /// and convolved with the kernel through the FFT, in O(n + num log num). This
+  /// approximation is accurate when the grid spacing is small compared to the bandwidth.
+  pub fn grid_between(&self, lo: T, hi: T, num: usize) -> Vector<T> {
+    let points = Vector::linspace(lo, hi, num);
+    let n = self.data.len();
+    if n <= DIRECT_MAX_SAMPLES || num < 2 || self.data[0] < lo || self.data[n - 1] > hi {
+      return self.pdf_many(&points);
+    }
+    let dx = (hi - lo) / T::from(num - 1).unwrap();
+    let mut weights = vec![T::zero(); num];
+    for &xi in &self.data {
+      let pos = (xi - lo) / dx;
+      let k = pos.floor().to_usize().unwrap_or(0).min(num - 2);
+      let frac = pos - T::from(k).unwrap();
+      weights[k] = weights[k] + T::one() - frac;
+      weights[k + 1] = weights[k + 1] + frac;
+    }
+    let h = self.bandwidth * self.scale;
+    // Kernel at every grid offset that can reach another grid point within its support.
+    let reach = (h * self.kernel.support::<T>() / dx)
+      .floor()
+      .to_usize()
+      .unwrap_or(num - 1)
+      .min(num - 1);
+    let kernel: Vec<T> = (0..=2 * reach)
+      .map(|j| {
+        let offset = T::from(j).unwrap() - T::from(reach).unwrap();
+        self.kernel.pdf(offset * dx / h)
+      })
+      .collect();
+    let full = weights.into_vector().convolve_with(
+      &kernel.into_vector(),
+      ConvolveMode::Full,
+      ConvolveMethod::Auto,
+    );
+    let norm = T::from(n).unwrap() * h;
+    full[reach..reach + num]
+      .iter()
+      .map(|&v| v / norm)
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// The sorted samples whose kernels are non-zero at `x`.
+  fn near(&self, x: T) -> &[T] {
+    let reach = self.bandwidth * self.scale * self.kernel.support::<T>();
+    let start = self.data.partition_point(|&xi| xi < x - reach);
+    let end = self.data.partition_point(|&xi| xi <= x + reach);
+    &self.data[start..end]
+  }
+}
+
+/// Trait providing kernel density estimation for `Vector<T>`.
+pub trait KdeOps<T> {
+  /// Builds a kernel density estimate of the values. See [`Kde::new`].
+  fn kde(&self, kernel: Kernel, bandwidth: Bandwidth<T>) -> Result<Kde<T>, MathError>;
+}
+
+impl<T> KdeOps<T> for Vector<T>
+where
+  T: Float + FloatConst,
+{
+  fn kde(&self, kernel: Kernel, bandwidth: Bandwidth<T>) -> Result<Kde<T>, MathError> {
+    Kde::new(self, kernel, bandwidth)
+  }
+}
+
+/// `factor * min(stddev, iqr / 1.34) * n^(-1/5)`, falling back to whichever spread is
+/// non-zero.
+fn rule_of_thumb<T: Float>(data: &Vector<T>, n: usize, factor: T) -> Result<T, MathError> {
+  let stddev = data.stddev().unwrap_or(T::zero());
+  let iqr = data.iqr().unwrap_or(T::zero()) / T::from(1.34).unwrap();
+  let spread = match (stddev > T::zero(), iqr > T::zero()) {
+    (true, true) => stddev.min(iqr),
+    (true, false) => stddev,
+    (false, true) => iqr,
+    (false, false) => {
+      return Err(MathError::InvalidArgument(
+        "bandwidth rules require at least two distinct values".to_string(),
+      ))
+    }
+  };
+  Ok(factor * spread * T::from(n).unwrap().powf(T::from(-0.2).unwrap()))
+}
@@ -4,19 +4,71 @@
 //! It supports both `f32` and `f64` types, and includes methods for common statistical measures,
 //! normalization, sorting, and vector arithmetic.
 
+pub mod autocorrelation;
+pub mod calculus;
+pub mod columns;
 pub mod conversion;
+pub mod convolution;
+pub mod decomposition;
+pub mod distributions;
+pub mod ecdf;
+pub mod error;
+pub mod ewm;
+pub mod fft;
+pub mod filter;
+pub mod histogram;
+pub mod impute;
+pub mod interp;
+pub mod kde;
+pub mod linalg;
+pub mod math;
+pub mod matrix;
 pub mod normalize;
 pub mod operations;
+pub mod pca;
+pub mod regression;
+pub mod robust;
+pub mod rolling;
+pub mod series;
+pub mod smoothing;
 pub mod sort;
+pub mod special;
+pub mod spectral;
 pub mod statistics;
 pub mod summary;
 pub mod vector;
 
 // Re-exporting for easy access
+pub use autocorrelation::*;
+pub use calculus::*;
+pub use columns::*;
 pub use conversion::*;
+pub use convolution::*;
+pub use decomposition::*;
+pub use distributions::*;
+pub use ecdf::*;
+pub use error::*;
+pub use ewm::*;
+pub use fft::*;
+pub use filter::*;
+pub use histogram::*;
+pub use impute::*;
+pub use interp::*;
+pub use kde::*;
+pub use linalg::*;
+pub use math::*;
+pub use matrix::*;
 pub use normalize::*;
 pub use operations::*;
+pub use pca::*;
+pub use regression::*;
+pub use robust::*;
+pub use rolling::*;
+pub use series::*;
+pub use smoothing::*;
 pub use sort::*;
+pub use special::*;
+pub use spectral::*;
 pub use statistics::*;
 pub use summary::*;
 pub use vector::*;
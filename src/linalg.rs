@@ -0,0 +1,171 @@
+//! Linear algebra primitives, distances and similarity measures for `Vector<T>`.
+//!
+//! Unlike the statistical methods, these treat NaN as an ordinary value, so a NaN
+//! element propagates into the result.
+
+use crate::error::{check_same_len, MathError};
+use crate::vector::Vector;
+use num_traits::Float;
+
+/// Trait providing linear algebra primitives for `Vector<T>`.
+pub trait LinAlg<T> {
+  /// Computes the dot product with another vector of the same length.
+  fn dot(&self, other: &Vector<T>) -> Result<T, MathError>;
+
+  /// Computes the Euclidean (L2) norm of the vector.
+  fn norm(&self) -> T;
+
+  /// Computes the cross product with another vector. Both vectors must be 3-dimensional.
+  fn cross(&self, other: &Vector<T>) -> Result<Vector<T>, MathError>;
+
+  /// Computes the outer product with another vector.
+  /// Row `i` of the result is `other` scaled by `self[i]`.
+  fn outer(&self, other: &Vector<T>) -> Vec<Vector<T>>;
+}
+
+/// Trait providing distance and similarity measures between two `Vector<T>` of the same length.
+pub trait Distance<T> {
+  /// Computes the Euclidean (L2) distance.
+  fn euclidean_distance(&self, other: &Vector<T>) -> Result<T, MathError>;
+
+  /// Computes the Manhattan (L1) distance.
+  fn manhattan_distance(&self, other: &Vector<T>) -> Result<T, MathError>;
+
+  /// Computes the Chebyshev (L-infinity) distance, the largest absolute difference.
+  fn chebyshev_distance(&self, other: &Vector<T>) -> Result<T, MathError>;
+
+  /// Computes the Minkowski distance of order `p`, which must be at least 1.
+  /// `p = 1` and `p = 2` give the Manhattan and Euclidean distances, and
+  /// an infinite `p` gives the Chebyshev distance.
+  fn minkowski_distance(&self, other: &Vector<T>, p: T) -> Result<T, MathError>;
+
+  /// Computes the cosine of the angle between the two vectors.
+  /// Returns NaN if either vector has zero norm.
+  fn cosine_similarity(&self, other: &Vector<T>) -> Result<T, MathError>;
+
+  /// Computes the angle between the two vectors, in radians within `[0, pi]`.
+  /// Returns NaN if either vector has zero norm.
+  fn angle_between(&self, other: &Vector<T>) -> Result<T, MathError>;
+}
+
+impl<T> LinAlg<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn dot(&self, other: &Vector<T>) -> Result<T, MathError> {
+    check_same_len(self, other)?;
+    Ok(
+      self
+        .iter()
+        .zip(other.iter())
+        .fold(T::zero(), |acc, (&a, &b)| acc + a * b),
+    )
+  }
+
+  fn norm(&self) -> T {
+    self.iter().fold(T::zero(), |acc, &x| x.hypot(acc))
+  }
+
+  fn cross(&self, other: &Vector<T>) -> Result<Vector<T>, MathError> {
+    for v in [self, other] {
+      if v.len() != 3 {
+        return Err(MathError::InvalidLength {
+          expected: 3,
+          found: v.len(),
+        });
+      }
+    }
+    let (a, b) = (self, other);
+    Ok(Vector::new(vec![
+      a[1] * b[2] - a[2] * b[1],
+      a[2] * b[0] - a[0] * b[2],
+      a[0] * b[1] - a[1] * b[0],
+    ]))
+  }
+
+  fn outer(&self, other: &Vector<T>) -> Vec<Vector<T>> {
+    self
+      .iter()
+      .map(|&a| Vector::new(other.iter().map(|&b| a * b).collect()))
+      .collect()
+  }
+}
+
+impl<T> Distance<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn euclidean_distance(&self, other: &Vector<T>) -> Result<T, MathError> {
+    check_same_len(self, other)?;
+    Ok(
+      self
+        .iter()
+        .zip(other.iter())
+        .fold(T::zero(), |acc, (&a, &b)| (a - b).hypot(acc)),
+    )
+  }
+
+  fn manhattan_distance(&self, other: &Vector<T>) -> Result<T, MathError> {
+    check_same_len(self, other)?;
+    Ok(
+      self
+        .iter()
+        .zip(other.iter())
+        .fold(T::zero(), |acc, (&a, &b)| acc + (a - b).abs()),
+    )
+  }
+
+  fn chebyshev_distance(&self, other: &Vector<T>) -> Result<T, MathError> {
+    check_same_len(self, other)?;
+    // Fold with `>` rather than `Float::max` so that NaN propagates.
+    Ok(
+      self
+        .iter()
+        .zip(other.iter())
+        .fold(T::zero(), |acc, (&a, &b)| {
+          let d = (a - b).abs();
+          if d > acc || d.is_nan() {
+            d
+          } else {
+            acc
+          }
+        }),
+    )
+  }
+
+  fn minkowski_distance(&self, other: &Vector<T>, p: T) -> Result<T, MathError> {
+    if p.is_nan() || p < T::one() {
+      return Err(MathError::InvalidArgument(
+        "Minkowski order p must be at least 1".to_string(),
+      ));
+    }
+    if p.is_infinite() {
+      return self.chebyshev_distance(other);
+    }
+    check_same_len(self, other)?;
+    let sum = self
+      .iter()
+      .zip(other.iter())
+      .fold(T::zero(), |acc, (&a, &b)| acc + (a - b).abs().powf(p));
+    Ok(sum.powf(p.recip()))
+  }
+
+  fn cosine_similarity(&self, other: &Vector<T>) -> Result<T, MathError> {
+    let dot = self.dot(other)?;
+    let denom = self.norm() * other.norm();
+    if denom == T::zero() {
+      Ok(T::nan())
+    } else {
+      Ok(dot / denom)
+    }
+  }
+
+  fn angle_between(&self, other: &Vector<T>) -> Result<T, MathError> {
+    let cos = self.cosine_similarity(other)?;
+    if cos.is_nan() {
+      return Ok(cos);
+    }
+    // Rounding can push the cosine slightly outside [-1, 1].
+    Ok(cos.max(-T::one()).min(T::one()).acos())
+  }
+}
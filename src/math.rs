@@ -0,0 +1,169 @@
+//! Element-wise mathematical functions for `Vector<T>`.
+
+use crate::operations::broadcast_zip;
+use crate::vector::Vector;
+use num_traits::Float;
+
+/// Trait providing element-wise mathematical functions for `Vector<T>`.
+/// Every method returns a new vector of the same length; NaN values propagate.
+pub trait VectorMath<T> {
+  /// Applies `f` to each element and returns a new vector.
+  fn map<F>(&self, f: F) -> Vector<T>
+  where
+    F: FnMut(T) -> T;
+
+  /// Applies `f` to each pair of elements from this vector and `other` and returns a new vector.
+  /// A length-1 vector is broadcast against the other, as in `VectorOps`.
+  fn zip_map<F>(&self, other: &Vector<T>, f: F) -> Vector<T>
+  where
+    F: FnMut(T, T) -> T;
+
+  /// Computes `e^x` for each element.
+  fn exp(&self) -> Vector<T>;
+
+  /// Computes `e^x - 1` for each element, accurate for values near zero.
+  fn expm1(&self) -> Vector<T>;
+
+  /// Computes the natural logarithm of each element.
+  fn ln(&self) -> Vector<T>;
+
+  /// Computes the base-10 logarithm of each element.
+  fn log10(&self) -> Vector<T>;
+
+  /// Computes the base-2 logarithm of each element.
+  fn log2(&self) -> Vector<T>;
+
+  /// Computes `ln(1 + x)` for each element, accurate for values near zero.
+  fn log1p(&self) -> Vector<T>;
+
+  /// Computes the square root of each element.
+  fn sqrt(&self) -> Vector<T>;
+
+  /// Computes the cube root of each element.
+  fn cbrt(&self) -> Vector<T>;
+
+  /// Raises each element to an integer power.
+  fn powi(&self, n: i32) -> Vector<T>;
+
+  /// Raises each element to a floating-point power.
+  fn powf(&self, n: T) -> Vector<T>;
+
+  /// Computes the absolute value of each element.
+  fn abs(&self) -> Vector<T>;
+
+  /// Returns the sign of each element as `1`, `-1` or NaN.
+  fn signum(&self) -> Vector<T>;
+
+  /// Computes the sine of each element, given in radians.
+  fn sin(&self) -> Vector<T>;
+
+  /// Computes the cosine of each element, given in radians.
+  fn cos(&self) -> Vector<T>;
+
+  /// Computes the tangent of each element, given in radians.
+  fn tan(&self) -> Vector<T>;
+
+  /// Computes the arcsine of each element, in radians.
+  fn asin(&self) -> Vector<T>;
+
+  /// Computes the arccosine of each element, in radians.
+  fn acos(&self) -> Vector<T>;
+
+  /// Computes the arctangent of each element, in radians.
+  fn atan(&self) -> Vector<T>;
+
+  /// Computes the hyperbolic sine of each element.
+  fn sinh(&self) -> Vector<T>;
+
+  /// Computes the hyperbolic cosine of each element.
+  fn cosh(&self) -> Vector<T>;
+
+  /// Computes the hyperbolic tangent of each element.
+  fn tanh(&self) -> Vector<T>;
+
+  /// Computes the inverse hyperbolic sine of each element.
+  fn asinh(&self) -> Vector<T>;
+
+  /// Computes the inverse hyperbolic cosine of each element.
+  fn acosh(&self) -> Vector<T>;
+
+  /// Computes the inverse hyperbolic tangent of each element.
+  fn atanh(&self) -> Vector<T>;
+
+  /// Rounds each element down to the nearest integer.
+  fn floor(&self) -> Vector<T>;
+
+  /// Rounds each element up to the nearest integer.
+  fn ceil(&self) -> Vector<T>;
+
+  /// Rounds each element to the nearest integer, with halfway cases away from zero.
+  fn round(&self) -> Vector<T>;
+
+  /// Truncates the fractional part of each element.
+  fn trunc(&self) -> Vector<T>;
+
+  /// Restricts each element to the interval `[min, max]`. NaN values are left unchanged.
+  fn clamp(&self, min: T, max: T) -> Vector<T>;
+}
+
+/// Implements unary `VectorMath` methods that forward to the `Float` method of the same name.
+macro_rules! impl_unary {
+  ($($name:ident)*) => ($(
+    fn $name(&self) -> Vector<T> {
+      self.map(|x| x.$name())
+    }
+  )*)
+}
+
+impl<T> VectorMath<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn map<F>(&self, f: F) -> Vector<T>
+  where
+    F: FnMut(T) -> T,
+  {
+    let data = self.iter().copied().map(f).collect();
+    Vector::new(data)
+  }
+
+  fn zip_map<F>(&self, other: &Vector<T>, f: F) -> Vector<T>
+  where
+    F: FnMut(T, T) -> T,
+  {
+    let data = broadcast_zip(self, other, "zip_map", f);
+    Vector::new(data)
+  }
+
+  impl_unary!(exp ln log10 log2 sqrt cbrt abs signum);
+  impl_unary!(sin cos tan asin acos atan sinh cosh tanh asinh acosh atanh);
+  impl_unary!(floor ceil round trunc);
+
+  fn expm1(&self) -> Vector<T> {
+    self.map(|x| x.exp_m1())
+  }
+
+  fn log1p(&self) -> Vector<T> {
+    self.map(|x| x.ln_1p())
+  }
+
+  fn powi(&self, n: i32) -> Vector<T> {
+    self.map(|x| x.powi(n))
+  }
+
+  fn powf(&self, n: T) -> Vector<T> {
+    self.map(|x| x.powf(n))
+  }
+
+  fn clamp(&self, min: T, max: T) -> Vector<T> {
+    self.map(|x| {
+      if x < min {
+        min
+      } else if x > max {
+        max
+      } else {
+        x
+      }
+    })
+  }
+}
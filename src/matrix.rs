@@ -0,0 +1,456 @@
+//! Defines the dense, row-major `Matrix<T>` type and its core operations.
+
+use crate::error::MathError;
+use crate::operations::VectorOps;
+use crate::vector::Vector;
+use num_traits::Float;
+use std::ops::{Add, Index, IndexMut, Sub};
+
+/// Edge length of the square tiles used by `matmul`, chosen so that three tiles of
+/// `f64` fit comfortably in L1 cache.
+const BLOCK: usize = 64;
+
+/// A dense matrix stored in row-major order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix<T> {
+  data: Vector<T>,
+  rows: usize,
+  cols: usize,
+}
+
+impl<T> Matrix<T> {
+  /// Creates a `rows x cols` matrix from row-major data.
+  /// Returns an error if `data` does not have exactly `rows * cols` elements.
+  pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, MathError> {
+    if data.len() != rows * cols {
+      return Err(MathError::LengthMismatch {
+        expected: rows * cols,
+        found: data.len(),
+      });
+    }
+    Ok(Matrix {
+      data: Vector::new(data),
+      rows,
+      cols,
+    })
+  }
+
+  /// Creates a matrix whose rows are the given vectors.
+  /// Returns an error if the rows do not all have the same length.
+  pub fn from_rows(rows: Vec<Vector<T>>) -> Result<Self, MathError> {
+    let nrows = rows.len();
+    let ncols = rows.first().map_or(0, |r| r.len());
+    let mut data = Vec::with_capacity(nrows * ncols);
+    for row in rows {
+      if row.len() != ncols {
+        return Err(MathError::LengthMismatch {
+          expected: ncols,
+          found: row.len(),
+        });
+      }
+      data.extend(row.into_vec());
+    }
+    Matrix::from_vec(nrows, ncols, data)
+  }
+
+  /// Creates a `1 x n` matrix from a vector.
+  pub fn row_vector(v: Vector<T>) -> Self {
+    let cols = v.len();
+    Matrix {
+      data: v,
+      rows: 1,
+      cols,
+    }
+  }
+
+  /// Creates an `n x 1` matrix from a vector.
+  pub fn col_vector(v: Vector<T>) -> Self {
+    let rows = v.len();
+    Matrix {
+      data: v,
+      rows,
+      cols: 1,
+    }
+  }
+
+  /// Returns the number of rows.
+  pub fn nrows(&self) -> usize {
+    self.rows
+  }
+
+  /// Returns the number of columns.
+  pub fn ncols(&self) -> usize {
+    self.cols
+  }
+
+  /// Returns `(rows, cols)`.
+  pub fn shape(&self) -> (usize, usize) {
+    (self.rows, self.cols)
+  }
+
+  /// Returns `true` if the matrix has as many rows as columns.
+  pub fn is_square(&self) -> bool {
+    self.rows == self.cols
+  }
+
+  /// Returns the element at `(row, col)`, or `None` if out of bounds.
+  pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+    if row < self.rows && col < self.cols {
+      self.data.get(row * self.cols + col)
+    } else {
+      None
+    }
+  }
+
+  /// Returns the underlying row-major data.
+  pub fn as_slice(&self) -> &[T] {
+    &self.data
+  }
+
+  /// Returns the underlying row-major data mutably.
+  pub fn as_mut_slice(&mut self) -> &mut [T] {
+    &mut self.data
+  }
+
+  /// Consumes the matrix and returns its row-major data.
+  pub fn into_vector(self) -> Vector<T> {
+    self.data
+  }
+
+  /// Returns row `i` as a slice without copying. Panics if `i` is out of bounds.
+  pub fn row_slice(&self, i: usize) -> &[T] {
+    assert!(
+      i < self.rows,
+      "Row index {} out of bounds for {} rows.",
+      i,
+      self.rows
+    );
+    &self.data[i * self.cols..(i + 1) * self.cols]
+  }
+}
+
+impl<T> Matrix<T>
+where
+  T: Float + Copy,
+{
+  /// Creates a `rows x cols` matrix whose element `(i, j)` is `f(i, j)`.
+  pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+  where
+    F: FnMut(usize, usize) -> T,
+  {
+    let mut data = Vec::with_capacity(rows * cols);
+    for i in 0..rows {
+      for j in 0..cols {
+        data.push(f(i, j));
+      }
+    }
+    Matrix {
+      data: Vector::new(data),
+      rows,
+      cols,
+    }
+  }
+
+  /// Creates a matrix whose columns are the given vectors.
+  /// Returns an error if the columns do not all have the same length.
+  pub fn from_cols(cols: Vec<Vector<T>>) -> Result<Self, MathError> {
+    let nrows = cols.first().map_or(0, |c| c.len());
+    if let Some(c) = cols.iter().find(|c| c.len() != nrows) {
+      return Err(MathError::LengthMismatch {
+        expected: nrows,
+        found: c.len(),
+      });
+    }
+    Ok(Matrix::from_fn(nrows, cols.len(), |i, j| cols[j][i]))
+  }
+
+  /// Creates a `rows x cols` matrix of zeros.
+  pub fn zeros(rows: usize, cols: usize) -> Self {
+    Matrix {
+      data: Vector::new(vec![T::zero(); rows * cols]),
+      rows,
+      cols,
+    }
+  }
+
+  /// Creates a `rows x cols` matrix of ones.
+  pub fn ones(rows: usize, cols: usize) -> Self {
+    Matrix {
+      data: Vector::new(vec![T::one(); rows * cols]),
+      rows,
+      cols,
+    }
+  }
+
+  /// Creates the `n x n` identity matrix.
+  pub fn identity(n: usize) -> Self {
+    Matrix::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+  }
+
+  /// Creates an `n x n` matrix with `diag` on its diagonal and zeros elsewhere.
+  pub fn from_diagonal(diag: &Vector<T>) -> Self {
+    Matrix::from_fn(diag.len(), diag.len(), |i, j| {
+      if i == j {
+        diag[i]
+      } else {
+        T::zero()
+      }
+    })
+  }
+
+  /// Returns row `i` as a new vector. Panics if `i` is out of bounds.
+  pub fn row(&self, i: usize) -> Vector<T> {
+    Vector::new(self.row_slice(i).to_vec())
+  }
+
+  /// Returns column `j` as a new vector. Panics if `j` is out of bounds.
+  pub fn col(&self, j: usize) -> Vector<T> {
+    assert!(
+      j < self.cols,
+      "Column index {} out of bounds for {} columns.",
+      j,
+      self.cols
+    );
+    Vector::new(
+      (0..self.rows)
+        .map(|i| self.data[i * self.cols + j])
+        .collect(),
+    )
+  }
+
+  /// Returns all rows as vectors.
+  pub fn rows(&self) -> Vec<Vector<T>> {
+    (0..self.rows).map(|i| self.row(i)).collect()
+  }
+
+  /// Returns all columns as vectors.
+  pub fn cols(&self) -> Vec<Vector<T>> {
+    (0..self.cols).map(|j| self.col(j)).collect()
+  }
+
+  /// Returns the diagonal as a vector.
+  pub fn diagonal(&self) -> Vector<T> {
+    let n = self.rows.min(self.cols);
+    Vector::new((0..n).map(|i| self.data[i * self.cols + i]).collect())
+  }
+
+  /// Returns the transpose of the matrix.
+  pub fn transpose(&self) -> Matrix<T> {
+    Matrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)])
+  }
+
+  /// Computes the matrix-vector product `self * v`.
+  /// Returns an error unless `v` has one element per column.
+  pub fn matvec(&self, v: &Vector<T>) -> Result<Vector<T>, MathError> {
+    if v.len() != self.cols {
+      return Err(MathError::LengthMismatch {
+        expected: self.cols,
+        found: v.len(),
+      });
+    }
+    let data = (0..self.rows)
+      .map(|i| {
+        self
+          .row_slice(i)
+          .iter()
+          .zip(v.iter())
+          .fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+      })
+      .collect();
+    Ok(Vector::new(data))
+  }
+
+  /// Computes the matrix product `self * other` using cache-blocked tiles.
+  /// Returns an error unless `self` has as many columns as `other` has rows.
+  pub fn matmul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+    if self.cols != other.rows {
+      return Err(MathError::ShapeMismatch {
+        expected: (self.cols, other.cols),
+        found: other.shape(),
+      });
+    }
+    let (n, m, p) = (self.rows, self.cols, other.cols);
+    let mut out = Matrix::zeros(n, p);
+    for ii in (0..n).step_by(BLOCK) {
+      for kk in (0..m).step_by(BLOCK) {
+        for jj in (0..p).step_by(BLOCK) {
+          for i in ii..(ii + BLOCK).min(n) {
+            for k in kk..(kk + BLOCK).min(m) {
+              let a = self.data[i * m + k];
+              let b_row = &other.data[k * p + jj..k * p + (jj + BLOCK).min(p)];
+              let out_row = &mut out.data[i * p + jj..i * p + (jj + BLOCK).min(p)];
+              for (o, &b) in out_row.iter_mut().zip(b_row.iter()) {
+                *o = *o + a * b;
+              }
+            }
+          }
+        }
+      }
+    }
+    Ok(out)
+  }
+
+  /// Applies `f` to each element and returns a new matrix.
+  pub fn map<F>(&self, f: F) -> Matrix<T>
+  where
+    F: FnMut(T) -> T,
+  {
+    Matrix {
+      data: Vector::new(self.data.iter().copied().map(f).collect()),
+      rows: self.rows,
+      cols: self.cols,
+    }
+  }
+
+  /// Adds another matrix element-wise. See [`Matrix::zip_broadcast`] for the shape rules.
+  pub fn add_mat(&self, other: &Matrix<T>) -> Matrix<T> {
+    self.zip_broadcast(other, "addition", |a, b| a.add_vec(b))
+  }
+
+  /// Subtracts another matrix element-wise. See [`Matrix::zip_broadcast`] for the shape rules.
+  pub fn sub_mat(&self, other: &Matrix<T>) -> Matrix<T> {
+    self.zip_broadcast(other, "subtraction", |a, b| a.sub_vec(b))
+  }
+
+  /// Multiplies by another matrix element-wise (the Hadamard product).
+  /// See [`Matrix::zip_broadcast`] for the shape rules.
+  pub fn mul_mat(&self, other: &Matrix<T>) -> Matrix<T> {
+    self.zip_broadcast(other, "multiplication", |a, b| a.mul_vec(b))
+  }
+
+  /// Divides by another matrix element-wise. See [`Matrix::zip_broadcast`] for the shape rules.
+  pub fn div_mat(&self, other: &Matrix<T>) -> Matrix<T> {
+    self.zip_broadcast(other, "division", |a, b| a.div_vec(b))
+  }
+
+  /// Computes the modulus by another matrix element-wise.
+  /// See [`Matrix::zip_broadcast`] for the shape rules.
+  pub fn rem_mat(&self, other: &Matrix<T>) -> Matrix<T> {
+    self.zip_broadcast(other, "modulus", |a, b| a.rem_vec(b))
+  }
+
+  /// Adds a scalar to each element.
+  pub fn add_scalar(&self, scalar: T) -> Matrix<T> {
+    self.with_data(self.data.add_scalar(scalar))
+  }
+
+  /// Subtracts a scalar from each element.
+  pub fn sub_scalar(&self, scalar: T) -> Matrix<T> {
+    self.with_data(self.data.sub_scalar(scalar))
+  }
+
+  /// Multiplies each element by a scalar.
+  pub fn mul_scalar(&self, scalar: T) -> Matrix<T> {
+    self.with_data(self.data.mul_scalar(scalar))
+  }
+
+  /// Divides each element by a scalar.
+  pub fn div_scalar(&self, scalar: T) -> Matrix<T> {
+    self.with_data(self.data.div_scalar(scalar))
+  }
+
+  /// Computes the modulus of each element by a scalar.
+  pub fn rem_scalar(&self, scalar: T) -> Matrix<T> {
+    self.with_data(self.data.rem_scalar(scalar))
+  }
+
+  /// Combines two matrices row by row with a `VectorOps` method, broadcasting along
+  /// any axis where one operand has size 1, as numpy does. For example, a `1 x n`
+  /// matrix built with [`Matrix::row_vector`] is applied to every row of an `m x n`
+  /// matrix, and an `m x 1` matrix built with [`Matrix::col_vector`] to every column.
+  /// Panics if the shapes cannot be broadcast together.
+  pub fn zip_broadcast<F>(&self, other: &Matrix<T>, op: &str, f: F) -> Matrix<T>
+  where
+    F: Fn(&Vector<T>, &Vector<T>) -> Vector<T>,
+  {
+    if self.shape() == other.shape() {
+      return self.with_data(f(&self.data, &other.data));
+    }
+    let broadcast = |a: usize, b: usize| {
+      crate::operations::broadcast_len(a, b).unwrap_or_else(|| {
+        panic!(
+          "Matrices of shape {:?} and {:?} cannot be broadcast together for {}.",
+          self.shape(),
+          other.shape(),
+          op
+        )
+      })
+    };
+    let rows = broadcast(self.rows, other.rows);
+    let cols = broadcast(self.cols, other.cols);
+    let mut data = Vec::with_capacity(rows * cols);
+    for i in 0..rows {
+      let a = self.row(if self.rows == 1 { 0 } else { i });
+      let b = other.row(if other.rows == 1 { 0 } else { i });
+      data.extend(f(&a, &b).into_vec());
+    }
+    Matrix {
+      data: Vector::new(data),
+      rows,
+      cols,
+    }
+  }
+
+  /// Returns a matrix with the same shape as `self` holding `data`.
+  fn with_data(&self, data: Vector<T>) -> Matrix<T> {
+    Matrix {
+      data,
+      rows: self.rows,
+      cols: self.cols,
+    }
+  }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+  type Output = T;
+
+  fn index(&self, (row, col): (usize, usize)) -> &T {
+    assert!(
+      row < self.rows && col < self.cols,
+      "Index ({}, {}) out of bounds for a {}x{} matrix.",
+      row,
+      col,
+      self.rows,
+      self.cols
+    );
+    &self.data[row * self.cols + col]
+  }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+  fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+    assert!(
+      row < self.rows && col < self.cols,
+      "Index ({}, {}) out of bounds for a {}x{} matrix.",
+      row,
+      col,
+      self.rows,
+      self.cols
+    );
+    &mut self.data[row * self.cols + col]
+  }
+}
+
+/// Implement operator overloading for `Matrix<T> + Matrix<T>`.
+impl<T> Add for &Matrix<T>
+where
+  T: Float + Copy,
+{
+  type Output = Matrix<T>;
+
+  fn add(self, rhs: &Matrix<T>) -> Matrix<T> {
+    self.add_mat(rhs)
+  }
+}
+
+/// Implement operator overloading for `Matrix<T> - Matrix<T>`.
+impl<T> Sub for &Matrix<T>
+where
+  T: Float + Copy,
+{
+  type Output = Matrix<T>;
+
+  fn sub(self, rhs: &Matrix<T>) -> Matrix<T> {
+    self.sub_mat(rhs)
+  }
+}
@@ -2,9 +2,14 @@
 
 use crate::vector::Vector;
 use num_traits::{Float, Num};
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::ops::{
+  Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 
 /// Trait providing arithmetic operations for `Vector<T>`.
+///
+/// Vector-vector operations broadcast: a vector of length 1 behaves like a scalar
+/// applied to every element of the other vector. Any other length mismatch panics.
 pub trait VectorOps<T> {
   /// Adds another vector to this vector and returns a new vector.
   fn add_vec(&self, other: &Vector<T>) -> Vector<T>;
@@ -42,72 +47,27 @@ where
   T: Num + Copy + PartialOrd + Float,
 {
   fn add_vec(&self, other: &Vector<T>) -> Vector<T> {
-    assert_eq!(
-      self.len(),
-      other.len(),
-      "Vectors must be of the same length for addition."
-    );
-    let data = self
-      .iter()
-      .zip(other.iter())
-      .map(|(&a, &b)| a + b)
-      .collect();
+    let data = broadcast_zip(self, other, "addition", |a, b| a + b);
     Vector::new(data)
   }
 
   fn sub_vec(&self, other: &Vector<T>) -> Vector<T> {
-    assert_eq!(
-      self.len(),
-      other.len(),
-      "Vectors must be of the same length for subtraction."
-    );
-    let data = self
-      .iter()
-      .zip(other.iter())
-      .map(|(&a, &b)| a - b)
-      .collect();
+    let data = broadcast_zip(self, other, "subtraction", |a, b| a - b);
     Vector::new(data)
   }
 
   fn mul_vec(&self, other: &Vector<T>) -> Vector<T> {
-    assert_eq!(
-      self.len(),
-      other.len(),
-      "Vectors must be of the same length for multiplication."
-    );
-    let data = self
-      .iter()
-      .zip(other.iter())
-      .map(|(&a, &b)| a * b)
-      .collect();
+    let data = broadcast_zip(self, other, "multiplication", |a, b| a * b);
     Vector::new(data)
   }
 
   fn div_vec(&self, other: &Vector<T>) -> Vector<T> {
-    assert_eq!(
-      self.len(),
-      other.len(),
-      "Vectors must be of the same length for division."
-    );
-    let data = self
-      .iter()
-      .zip(other.iter())
-      .map(|(&a, &b)| a / b)
-      .collect();
+    let data = broadcast_zip(self, other, "division", |a, b| a / b);
     Vector::new(data)
   }
 
   fn rem_vec(&self, other: &Vector<T>) -> Vector<T> {
-    assert_eq!(
-      self.len(),
-      other.len(),
-      "Vectors must be of the same length for modulus."
-    );
-    let data = self
-      .iter()
-      .zip(other.iter())
-      .map(|(&a, &b)| a % b)
-      .collect();
+    let data = broadcast_zip(self, other, "modulus", |a, b| a % b);
     Vector::new(data)
   }
 
@@ -137,6 +97,259 @@ where
   }
 }
 
+/// Trait providing arithmetic operations for `Vector<T>` that write their result into an
+/// existing buffer instead of allocating a new vector.
+///
+/// The `*_in_place` methods overwrite `self`. The `*_into` methods leave `self` untouched
+/// and write the result into `out`, which is resized to fit; no allocation happens when
+/// `out` already has enough capacity, so a scratch buffer can be reused across calls.
+pub trait VectorOpsInPlace<T> {
+  /// Adds another vector to this vector element-wise, in place.
+  fn add_vec_in_place(&mut self, other: &Vector<T>);
+
+  /// Subtracts another vector from this vector element-wise, in place.
+  fn sub_vec_in_place(&mut self, other: &Vector<T>);
+
+  /// Multiplies this vector by another vector element-wise, in place.
+  fn mul_vec_in_place(&mut self, other: &Vector<T>);
+
+  /// Divides this vector by another vector element-wise, in place.
+  fn div_vec_in_place(&mut self, other: &Vector<T>);
+
+  /// Computes the modulus of this vector by another vector element-wise, in place.
+  fn rem_vec_in_place(&mut self, other: &Vector<T>);
+
+  /// Adds a scalar to each element, in place.
+  fn add_scalar_in_place(&mut self, scalar: T);
+
+  /// Subtracts a scalar from each element, in place.
+  fn sub_scalar_in_place(&mut self, scalar: T);
+
+  /// Multiplies each element by a scalar, in place.
+  fn mul_scalar_in_place(&mut self, scalar: T);
+
+  /// Divides each element by a scalar, in place.
+  fn div_scalar_in_place(&mut self, scalar: T);
+
+  /// Computes the modulus of each element by a scalar, in place.
+  fn rem_scalar_in_place(&mut self, scalar: T);
+
+  /// Writes the element-wise sum of this vector and `other` into `out`.
+  fn add_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>);
+
+  /// Writes the element-wise difference of this vector and `other` into `out`.
+  fn sub_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>);
+
+  /// Writes the element-wise product of this vector and `other` into `out`.
+  fn mul_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>);
+
+  /// Writes the element-wise quotient of this vector and `other` into `out`.
+  fn div_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>);
+
+  /// Writes the element-wise modulus of this vector by `other` into `out`.
+  fn rem_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>);
+
+  /// Writes this vector plus a scalar into `out`.
+  fn add_scalar_into(&self, scalar: T, out: &mut Vector<T>);
+
+  /// Writes this vector minus a scalar into `out`.
+  fn sub_scalar_into(&self, scalar: T, out: &mut Vector<T>);
+
+  /// Writes this vector multiplied by a scalar into `out`.
+  fn mul_scalar_into(&self, scalar: T, out: &mut Vector<T>);
+
+  /// Writes this vector divided by a scalar into `out`.
+  fn div_scalar_into(&self, scalar: T, out: &mut Vector<T>);
+
+  /// Writes this vector modulo a scalar into `out`.
+  fn rem_scalar_into(&self, scalar: T, out: &mut Vector<T>);
+
+  /// Computes `self += a * x` element-wise.
+  fn axpy(&mut self, a: T, x: &Vector<T>);
+
+  /// Computes `self = self * a + b` element-wise.
+  fn mul_add_in_place(&mut self, a: T, b: T);
+}
+
+impl<T> VectorOpsInPlace<T> for Vector<T>
+where
+  T: Num + Copy + PartialOrd + Float,
+{
+  fn add_vec_in_place(&mut self, other: &Vector<T>) {
+    zip_in_place(self, other, "addition", |a, b| a + b);
+  }
+
+  fn sub_vec_in_place(&mut self, other: &Vector<T>) {
+    zip_in_place(self, other, "subtraction", |a, b| a - b);
+  }
+
+  fn mul_vec_in_place(&mut self, other: &Vector<T>) {
+    zip_in_place(self, other, "multiplication", |a, b| a * b);
+  }
+
+  fn div_vec_in_place(&mut self, other: &Vector<T>) {
+    zip_in_place(self, other, "division", |a, b| a / b);
+  }
+
+  fn rem_vec_in_place(&mut self, other: &Vector<T>) {
+    zip_in_place(self, other, "modulus", |a, b| a % b);
+  }
+
+  fn add_scalar_in_place(&mut self, scalar: T) {
+    self.iter_mut().for_each(|x| *x = *x + scalar);
+  }
+
+  fn sub_scalar_in_place(&mut self, scalar: T) {
+    self.iter_mut().for_each(|x| *x = *x - scalar);
+  }
+
+  fn mul_scalar_in_place(&mut self, scalar: T) {
+    self.iter_mut().for_each(|x| *x = *x * scalar);
+  }
+
+  fn div_scalar_in_place(&mut self, scalar: T) {
+    self.iter_mut().for_each(|x| *x = *x / scalar);
+  }
+
+  fn rem_scalar_in_place(&mut self, scalar: T) {
+    self.iter_mut().for_each(|x| *x = *x % scalar);
+  }
+
+  fn add_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>) {
+    zip_into(self, other, out, "addition", |a, b| a + b);
+  }
+
+  fn sub_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>) {
+    zip_into(self, other, out, "subtraction", |a, b| a - b);
+  }
+
+  fn mul_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>) {
+    zip_into(self, other, out, "multiplication", |a, b| a * b);
+  }
+
+  fn div_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>) {
+    zip_into(self, other, out, "division", |a, b| a / b);
+  }
+
+  fn rem_vec_into(&self, other: &Vector<T>, out: &mut Vector<T>) {
+    zip_into(self, other, out, "modulus", |a, b| a % b);
+  }
+
+  fn add_scalar_into(&self, scalar: T, out: &mut Vector<T>) {
+    map_into(self, out, |x| x + scalar);
+  }
+
+  fn sub_scalar_into(&self, scalar: T, out: &mut Vector<T>) {
+    map_into(self, out, |x| x - scalar);
+  }
+
+  fn mul_scalar_into(&self, scalar: T, out: &mut Vector<T>) {
+    map_into(self, out, |x| x * scalar);
+  }
+
+  fn div_scalar_into(&self, scalar: T, out: &mut Vector<T>) {
+    map_into(self, out, |x| x / scalar);
+  }
+
+  fn rem_scalar_into(&self, scalar: T, out: &mut Vector<T>) {
+    map_into(self, out, |x| x % scalar);
+  }
+
+  fn axpy(&mut self, a: T, x: &Vector<T>) {
+    zip_in_place(self, x, "axpy", |y, x| a * x + y);
+  }
+
+  fn mul_add_in_place(&mut self, a: T, b: T) {
+    self.iter_mut().for_each(|x| *x = *x * a + b);
+  }
+}
+
+/// Returns the length of the result of broadcasting vectors of length `a` and `b`,
+/// or `None` if they cannot be broadcast together.
+///
+/// Equal lengths are kept as they are, and a length-1 vector stretches to match the other.
+pub fn broadcast_len(a: usize, b: usize) -> Option<usize> {
+  if a == b || b == 1 {
+    Some(a)
+  } else if a == 1 {
+    Some(b)
+  } else {
+    None
+  }
+}
+
+/// Like `broadcast_len`, but panics with a message naming `op` on a mismatch.
+pub(crate) fn expect_broadcast_len(a: usize, b: usize, op: &str) -> usize {
+  broadcast_len(a, b).unwrap_or_else(|| {
+    panic!(
+      "Vectors must be of the same length, or one of them of length 1, for {}: {} vs {}.",
+      op, a, b
+    )
+  })
+}
+
+/// Applies `f` to each broadcast pair of elements and collects the results.
+pub(crate) fn broadcast_zip<T, F>(lhs: &[T], rhs: &[T], op: &str, mut f: F) -> Vec<T>
+where
+  T: Copy,
+  F: FnMut(T, T) -> T,
+{
+  let len = expect_broadcast_len(lhs.len(), rhs.len(), op);
+  if lhs.len() == rhs.len() {
+    lhs.iter().zip(rhs.iter()).map(|(&a, &b)| f(a, b)).collect()
+  } else if rhs.len() == 1 {
+    lhs.iter().map(|&a| f(a, rhs[0])).collect()
+  } else {
+    (0..len).map(|i| f(lhs[0], rhs[i])).collect()
+  }
+}
+
+/// Applies `f` to each broadcast pair of elements, storing the result in `lhs`.
+/// `lhs` only grows when it has length 1 and `rhs` is longer.
+fn zip_in_place<T, F>(lhs: &mut Vector<T>, rhs: &Vector<T>, op: &str, f: F)
+where
+  T: Copy,
+  F: Fn(T, T) -> T,
+{
+  let len = expect_broadcast_len(lhs.len(), rhs.len(), op);
+  if lhs.len() == len {
+    if rhs.len() == len {
+      for (a, &b) in lhs.iter_mut().zip(rhs.iter()) {
+        *a = f(*a, b);
+      }
+    } else {
+      let b = rhs[0];
+      lhs.iter_mut().for_each(|a| *a = f(*a, b));
+    }
+  } else {
+    let a = lhs[0];
+    lhs.clear();
+    lhs.extend(rhs.iter().map(|&b| f(a, b)));
+  }
+}
+
+/// Applies `f` to each broadcast pair of elements, storing the result in `out`.
+fn zip_into<T, F>(lhs: &Vector<T>, rhs: &Vector<T>, out: &mut Vector<T>, op: &str, f: F)
+where
+  T: Copy,
+  F: Fn(T, T) -> T,
+{
+  let len = expect_broadcast_len(lhs.len(), rhs.len(), op);
+  let (lhs_step, rhs_step) = (usize::from(lhs.len() == len), usize::from(rhs.len() == len));
+  out.clear();
+  out.extend((0..len).map(|i| f(lhs[i * lhs_step], rhs[i * rhs_step])));
+}
+
+/// Applies `f` to each element, storing the result in `out`.
+fn map_into<T, F>(src: &Vector<T>, out: &mut Vector<T>, f: F)
+where
+  T: Copy,
+  F: Fn(T) -> T,
+{
+  out.clear();
+  out.extend(src.iter().map(|&x| f(x)));
+}
+
 /// Implement operator overloading for `Vector<T> + Vector<T>`.
 impl<T> Add for &Vector<T>
 where
@@ -196,3 +409,161 @@ where
     self.rem_vec(rhs)
   }
 }
+
+/// Implements the owned and mixed-ownership forms of a vector operator, along with
+/// its compound assignment form. Owned operands are updated in place and returned,
+/// so only `&Vector<T> op &Vector<T>` and growing a broadcast length-1 operand allocate.
+macro_rules! impl_vector_op {
+  ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident, $in_place_fn:ident, $op:tt) => {
+    impl<T> $assign_trait<&Vector<T>> for Vector<T>
+    where
+      T: Num + Copy + PartialOrd + Float,
+    {
+      fn $assign_fn(&mut self, rhs: &Vector<T>) {
+        self.$in_place_fn(rhs);
+      }
+    }
+
+    impl<T> $assign_trait<Vector<T>> for Vector<T>
+    where
+      T: Num + Copy + PartialOrd + Float,
+    {
+      fn $assign_fn(&mut self, rhs: Vector<T>) {
+        self.$assign_fn(&rhs);
+      }
+    }
+
+    impl<T> $op_trait<Vector<T>> for Vector<T>
+    where
+      T: Num + Copy + PartialOrd + Float,
+    {
+      type Output = Vector<T>;
+
+      fn $op_fn(mut self, rhs: Vector<T>) -> Vector<T> {
+        self.$assign_fn(&rhs);
+        self
+      }
+    }
+
+    impl<T> $op_trait<&Vector<T>> for Vector<T>
+    where
+      T: Num + Copy + PartialOrd + Float,
+    {
+      type Output = Vector<T>;
+
+      fn $op_fn(mut self, rhs: &Vector<T>) -> Vector<T> {
+        self.$assign_fn(rhs);
+        self
+      }
+    }
+
+    impl<T> $op_trait<Vector<T>> for &Vector<T>
+    where
+      T: Num + Copy + PartialOrd + Float,
+    {
+      type Output = Vector<T>;
+
+      fn $op_fn(self, mut rhs: Vector<T>) -> Vector<T> {
+        if self.len() != rhs.len() {
+          return self $op &rhs;
+        }
+        for (b, &a) in rhs.iter_mut().zip(self.iter()) {
+          *b = a $op *b;
+        }
+        rhs
+      }
+    }
+  };
+}
+
+impl_vector_op!(Add, add, AddAssign, add_assign, add_vec_in_place, +);
+impl_vector_op!(Sub, sub, SubAssign, sub_assign, sub_vec_in_place, -);
+impl_vector_op!(Mul, mul, MulAssign, mul_assign, mul_vec_in_place, *);
+impl_vector_op!(Div, div, DivAssign, div_assign, div_vec_in_place, /);
+impl_vector_op!(Rem, rem, RemAssign, rem_assign, rem_vec_in_place, %);
+
+/// Implements a vector-scalar operator for concrete float types, with the scalar on
+/// either side. Scalars on the left cannot be implemented generically because of
+/// Rust's orphan rules, so `f32` and `f64` are covered explicitly.
+macro_rules! impl_scalar_op {
+  ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident, $in_place_fn:ident, $op:tt, $scalar_fn:ident; $($t:ty)*) => ($(
+    impl $assign_trait<$t> for Vector<$t> {
+      fn $assign_fn(&mut self, rhs: $t) {
+        self.$in_place_fn(rhs);
+      }
+    }
+
+    impl $op_trait<$t> for Vector<$t> {
+      type Output = Vector<$t>;
+
+      fn $op_fn(mut self, rhs: $t) -> Vector<$t> {
+        self.$assign_fn(rhs);
+        self
+      }
+    }
+
+    impl $op_trait<$t> for &Vector<$t> {
+      type Output = Vector<$t>;
+
+      fn $op_fn(self, rhs: $t) -> Vector<$t> {
+        self.$scalar_fn(rhs)
+      }
+    }
+
+    impl $op_trait<Vector<$t>> for $t {
+      type Output = Vector<$t>;
+
+      // The operand order matters for `-`, `/` and `%`, so `b op= self` is not an option.
+      #[allow(clippy::assign_op_pattern)]
+      fn $op_fn(self, mut rhs: Vector<$t>) -> Vector<$t> {
+        for b in rhs.iter_mut() {
+          *b = self $op *b;
+        }
+        rhs
+      }
+    }
+
+    impl $op_trait<&Vector<$t>> for $t {
+      type Output = Vector<$t>;
+
+      fn $op_fn(self, rhs: &Vector<$t>) -> Vector<$t> {
+        let data = rhs.iter().map(|&b| self $op b).collect();
+        Vector::new(data)
+      }
+    }
+  )*)
+}
+
+impl_scalar_op!(Add, add, AddAssign, add_assign, add_scalar_in_place, +, add_scalar; f32 f64);
+impl_scalar_op!(Sub, sub, SubAssign, sub_assign, sub_scalar_in_place, -, sub_scalar; f32 f64);
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign, mul_scalar_in_place, *, mul_scalar; f32 f64);
+impl_scalar_op!(Div, div, DivAssign, div_assign, div_scalar_in_place, /, div_scalar; f32 f64);
+impl_scalar_op!(Rem, rem, RemAssign, rem_assign, rem_scalar_in_place, %, rem_scalar; f32 f64);
+
+/// Implement operator overloading for `-Vector<T>`, negating in place.
+impl<T> Neg for Vector<T>
+where
+  T: Num + Copy + PartialOrd + Float,
+{
+  type Output = Vector<T>;
+
+  fn neg(mut self) -> Vector<T> {
+    for a in self.iter_mut() {
+      *a = -*a;
+    }
+    self
+  }
+}
+
+/// Implement operator overloading for `-&Vector<T>`.
+impl<T> Neg for &Vector<T>
+where
+  T: Num + Copy + PartialOrd + Float,
+{
+  type Output = Vector<T>;
+
+  fn neg(self) -> Vector<T> {
+    let data = self.iter().map(|&a| -a).collect();
+    Vector::new(data)
+  }
+}
@@ -0,0 +1,199 @@
+//! Principal component analysis over the columns of a `Matrix<T>`.
+
+use crate::columns::ColumnStatistics;
+use crate::decomposition::SymmetricEigen;
+use crate::error::MathError;
+use crate::matrix::Matrix;
+use crate::normalize::Normalize;
+use crate::statistics::Statistics;
+use crate::vector::Vector;
+use num_traits::{Float, FromPrimitive};
+use std::fmt;
+
+/// Options controlling how a [`Pca`] is fitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcaOptions {
+  /// Number of components to keep. `None` keeps one per input column.
+  pub n_components: Option<usize>,
+  /// Scale the projected components to unit variance.
+  pub whiten: bool,
+  /// Standardize each column with `Normalize::standardize` before fitting, so that
+  /// features on different scales contribute equally. Otherwise columns are only centered.
+  pub standardize: bool,
+}
+
+impl Default for PcaOptions {
+  fn default() -> Self {
+    PcaOptions {
+      n_components: None,
+      whiten: false,
+      standardize: true,
+    }
+  }
+}
+
+/// A fitted principal component analysis.
+///
+/// Rows of the input matrix are observations and columns are features. The
+/// covariance and explained variances divide by the number of observations, as
+/// `Statistics::var` does.
+#[derive(Debug, Clone)]
+pub struct Pca<T> {
+  /// Principal axes as rows, ordered by decreasing explained variance.
+  pub components: Matrix<T>,
+  /// Variance explained by each component.
+  pub explained_variance: Vector<T>,
+  /// Fraction of the total variance explained by each component.
+  pub explained_variance_ratio: Vector<T>,
+  /// Per-column mean of the training data.
+  pub mean: Vector<T>,
+  /// Per-column divisor applied after centering; all ones unless standardizing.
+  pub scale: Vector<T>,
+  /// Whether projections are scaled to unit variance.
+  pub whiten: bool,
+  /// Whether NaN inputs are replaced by zero after scaling, as `standardize` does.
+  standardize: bool,
+}
+
+impl<T> Pca<T>
+where
+  T: Float + FromPrimitive + Copy + PartialOrd + fmt::Display,
+{
+  /// Fits the principal axes of `data`.
+  /// Returns an error if `data` has fewer than two rows or `n_components` exceeds the column count.
+  pub fn fit(data: &Matrix<T>, options: PcaOptions) -> Result<Self, MathError> {
+    let (rows, cols) = data.shape();
+    if rows < 2 {
+      return Err(MathError::InvalidArgument(
+        "PCA requires at least two observations".to_string(),
+      ));
+    }
+    let k = options.n_components.unwrap_or(cols);
+    if k > cols {
+      return Err(MathError::InvalidArgument(format!(
+        "n_components ({}) exceeds the number of columns ({})",
+        k, cols
+      )));
+    }
+
+    let columns = data.cols();
+    let mean = Vector::new(
+      columns
+        .iter()
+        .map(|c| c.mean().unwrap_or(T::zero()))
+        .collect(),
+    );
+    let (scaled, scale): (Vec<Vector<T>>, Vec<T>) = if options.standardize {
+      columns
+        .iter()
+        .map(|c| {
+          let std = c.stddev().unwrap_or(T::one());
+          let std = if std == T::zero() { T::one() } else { std };
+          (c.standardize(), std)
+        })
+        .unzip()
+    } else {
+      columns
+        .iter()
+        .zip(mean.iter())
+        .map(|(c, &m)| (Vector::new(c.iter().map(|&x| x - m).collect()), T::one()))
+        .unzip()
+    };
+
+    let cov = scaled.as_slice().covariance()?;
+    let eigen = SymmetricEigen::new(&cov)?;
+    let total = eigen
+      .values
+      .iter()
+      .fold(T::zero(), |acc, &v| acc + v.max(T::zero()));
+    let explained_variance: Vector<T> = Vector::new(
+      eigen.values[..k]
+        .iter()
+        .map(|&v| v.max(T::zero()))
+        .collect(),
+    );
+    let explained_variance_ratio = Vector::new(
+      explained_variance
+        .iter()
+        .map(|&v| {
+          if total > T::zero() {
+            v / total
+          } else {
+            T::zero()
+          }
+        })
+        .collect(),
+    );
+    let components = Matrix::from_fn(k, cols, |i, j| eigen.vectors[(j, i)]);
+
+    Ok(Pca {
+      components,
+      explained_variance,
+      explained_variance_ratio,
+      mean,
+      scale: Vector::new(scale),
+      whiten: options.whiten,
+      standardize: options.standardize,
+    })
+  }
+
+  /// Projects `data` onto the principal axes, returning one row per observation
+  /// and one column per component.
+  pub fn transform(&self, data: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+    let cols = self.mean.len();
+    if data.ncols() != cols {
+      return Err(MathError::ShapeMismatch {
+        expected: (data.nrows(), cols),
+        found: data.shape(),
+      });
+    }
+    let centered = Matrix::from_fn(data.nrows(), cols, |i, j| {
+      let z = (data[(i, j)] - self.mean[j]) / self.scale[j];
+      if self.standardize && z.is_nan() {
+        T::zero()
+      } else {
+        z
+      }
+    });
+    let mut projected = centered.matmul(&self.components.transpose())?;
+    if self.whiten {
+      projected = projected.div_mat(&Matrix::row_vector(self.component_stddev()));
+    }
+    Ok(projected)
+  }
+
+  /// Maps projected data back to the original feature space.
+  /// Components that were dropped are lost, so this is only exact when all are kept.
+  pub fn inverse_transform(&self, projected: &Matrix<T>) -> Result<Matrix<T>, MathError> {
+    let k = self.components.nrows();
+    if projected.ncols() != k {
+      return Err(MathError::ShapeMismatch {
+        expected: (projected.nrows(), k),
+        found: projected.shape(),
+      });
+    }
+    let unwhitened = if self.whiten {
+      projected.mul_mat(&Matrix::row_vector(self.component_stddev()))
+    } else {
+      projected.clone()
+    };
+    let restored = unwhitened.matmul(&self.components)?;
+    Ok(
+      restored
+        .mul_mat(&Matrix::row_vector(self.scale.clone()))
+        .add_mat(&Matrix::row_vector(self.mean.clone())),
+    )
+  }
+
+  /// Standard deviation of each component, with zero variances mapped to one so
+  /// that whitening never divides by zero.
+  fn component_stddev(&self) -> Vector<T> {
+    Vector::new(
+      self
+        .explained_variance
+        .iter()
+        .map(|&v| if v > T::zero() { v.sqrt() } else { T::one() })
+        .collect(),
+    )
+  }
+}
@@ -0,0 +1,287 @@
+//! Linear regression: simple and multiple ordinary least squares, and polynomial fitting.
+//!
+//! Observations where any input is NaN are dropped before fitting, matching the way
+//! `Statistics` ignores NaN values.
+
+use crate::error::{check_same_len, MathError};
+use crate::matrix::Matrix;
+use crate::special::student_t_two_sided_p;
+use crate::vector::Vector;
+use num_traits::Float;
+
+/// Result of a simple linear regression `y = intercept + slope * x`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearRegression<T> {
+  /// Slope of the fitted line.
+  pub slope: T,
+  /// Intercept of the fitted line.
+  pub intercept: T,
+  /// Pearson correlation coefficient between `x` and `y`.
+  pub r_value: T,
+  /// Coefficient of determination, `r_value^2`.
+  pub r_squared: T,
+  /// Standard error of the slope.
+  pub slope_stderr: T,
+  /// Standard error of the intercept.
+  pub intercept_stderr: T,
+  /// t statistic for the hypothesis that the slope is zero.
+  pub slope_t: T,
+  /// t statistic for the hypothesis that the intercept is zero.
+  pub intercept_t: T,
+  /// Two-sided p-value for the slope t statistic.
+  pub slope_p: T,
+  /// Two-sided p-value for the intercept t statistic.
+  pub intercept_p: T,
+  /// Number of observations used.
+  pub n: usize,
+}
+
+/// Result of a multiple ordinary least squares regression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OlsResult<T> {
+  /// Fitted coefficients. When an intercept is fitted it comes first, followed by
+  /// one coefficient per column of the design matrix.
+  pub coefficients: Vector<T>,
+  /// Standard error of each coefficient.
+  pub std_errors: Vector<T>,
+  /// t statistic of each coefficient.
+  pub t_values: Vector<T>,
+  /// Two-sided p-value of each t statistic.
+  pub p_values: Vector<T>,
+  /// Coefficient of determination. Uncentered when no intercept is fitted.
+  pub r_squared: T,
+  /// R-squared adjusted for the number of coefficients.
+  pub adj_r_squared: T,
+  /// Residuals `y - X * coefficients` of the observations used.
+  pub residuals: Vector<T>,
+  /// Residual degrees of freedom, `n - coefficients.len()`.
+  pub df_resid: usize,
+}
+
+/// Fits `y = intercept + slope * x` by least squares and reports standard errors,
+/// t statistics and two-sided p-values for both parameters.
+/// Requires at least three observations and a non-constant `x`.
+pub fn linregress<T>(x: &Vector<T>, y: &Vector<T>) -> Result<LinearRegression<T>, MathError>
+where
+  T: Float + Copy,
+{
+  check_same_len(x, y)?;
+  let (xs, ys): (Vec<T>, Vec<T>) = x
+    .iter()
+    .zip(y.iter())
+    .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+    .map(|(&a, &b)| (a, b))
+    .unzip();
+  let n = xs.len();
+  if n < 3 {
+    return Err(MathError::InvalidArgument(
+      "linear regression requires at least three observations".to_string(),
+    ));
+  }
+  let nt = T::from(n).unwrap();
+  let mx = xs.iter().fold(T::zero(), |acc, &v| acc + v) / nt;
+  let my = ys.iter().fold(T::zero(), |acc, &v| acc + v) / nt;
+  let (mut sxx, mut sxy, mut syy) = (T::zero(), T::zero(), T::zero());
+  for (&a, &b) in xs.iter().zip(ys.iter()) {
+    let (dx, dy) = (a - mx, b - my);
+    sxx = sxx + dx * dx;
+    sxy = sxy + dx * dy;
+    syy = syy + dy * dy;
+  }
+  if sxx == T::zero() {
+    return Err(MathError::InvalidArgument(
+      "x must not be constant".to_string(),
+    ));
+  }
+
+  let slope = sxy / sxx;
+  let intercept = my - slope * mx;
+  let r_value = if syy == T::zero() {
+    T::zero()
+  } else {
+    (sxy / (sxx * syy).sqrt()).max(-T::one()).min(T::one())
+  };
+  let df = nt - T::from(2.0).unwrap();
+  let sse = (syy - slope * sxy).max(T::zero());
+  let s2 = sse / df;
+  let slope_stderr = (s2 / sxx).sqrt();
+  let intercept_stderr = (s2 * (nt.recip() + mx * mx / sxx)).sqrt();
+  let slope_t = slope / slope_stderr;
+  let intercept_t = intercept / intercept_stderr;
+
+  Ok(LinearRegression {
+    slope,
+    intercept,
+    r_value,
+    r_squared: r_value * r_value,
+    slope_stderr,
+    intercept_stderr,
+    slope_t,
+    intercept_t,
+    slope_p: student_t_two_sided_p(slope_t, df),
+    intercept_p: student_t_two_sided_p(intercept_t, df),
+    n,
+  })
+}
+
+/// Fits `y = X * beta` by ordinary least squares, where each row of `x` is an
+/// observation and each column a predictor. A leading intercept column is added
+/// when `intercept` is `true`.
+///
+/// The fit uses a QR decomposition. Returns an error if there are not more
+/// observations than coefficients, or if the predictors are linearly dependent.
+pub fn ols<T>(x: &Matrix<T>, y: &Vector<T>, intercept: bool) -> Result<OlsResult<T>, MathError>
+where
+  T: Float + Copy,
+{
+  if x.nrows() != y.len() {
+    return Err(MathError::LengthMismatch {
+      expected: x.nrows(),
+      found: y.len(),
+    });
+  }
+  let offset = usize::from(intercept);
+  let p = x.ncols() + offset;
+  let rows: Vec<usize> = (0..x.nrows())
+    .filter(|&i| !y[i].is_nan() && x.row_slice(i).iter().all(|v| !v.is_nan()))
+    .collect();
+  let n = rows.len();
+  if n <= p {
+    return Err(MathError::InvalidArgument(format!(
+      "OLS with {} coefficients requires more than {} observations, found {}",
+      p, p, n
+    )));
+  }
+
+  let design = Matrix::from_fn(n, p, |i, j| {
+    if j < offset {
+      T::one()
+    } else {
+      x[(rows[i], j - offset)]
+    }
+  });
+  let target = Vector::new(rows.iter().map(|&i| y[i]).collect());
+  let qr = design.qr()?;
+  let coefficients = qr.solve(&target)?;
+  let fitted = design.matvec(&coefficients)?;
+  let residuals = Vector::new(
+    target
+      .iter()
+      .zip(fitted.iter())
+      .map(|(&a, &b)| a - b)
+      .collect(),
+  );
+
+  let nt = T::from(n).unwrap();
+  let df_resid = n - p;
+  let dft = T::from(df_resid).unwrap();
+  let sse = residuals.iter().fold(T::zero(), |acc, &r| acc + r * r);
+  let center = if intercept {
+    target.iter().fold(T::zero(), |acc, &v| acc + v) / nt
+  } else {
+    T::zero()
+  };
+  let sst = target
+    .iter()
+    .fold(T::zero(), |acc, &v| acc + (v - center) * (v - center));
+  let r_squared = T::one() - sse / sst;
+  let adj_r_squared = T::one() - (T::one() - r_squared) * (nt - T::from(offset).unwrap()) / dft;
+
+  // Coefficient covariance is s^2 * (X^T X)^-1, with (X^T X)^-1 = R^-1 R^-T.
+  let s2 = sse / dft;
+  let r_inv = qr.r().inverse()?;
+  let std_errors = Vector::new(
+    (0..p)
+      .map(|i| {
+        let row = r_inv.row_slice(i);
+        (s2 * row.iter().fold(T::zero(), |acc, &v| acc + v * v)).sqrt()
+      })
+      .collect(),
+  );
+  let t_values = Vector::new(
+    coefficients
+      .iter()
+      .zip(std_errors.iter())
+      .map(|(&b, &se)| b / se)
+      .collect(),
+  );
+  let p_values = Vector::new(
+    t_values
+      .iter()
+      .map(|&t| student_t_two_sided_p(t, dft))
+      .collect(),
+  );
+
+  Ok(OlsResult {
+    coefficients,
+    std_errors,
+    t_values,
+    p_values,
+    r_squared,
+    adj_r_squared,
+    residuals,
+    df_resid,
+  })
+}
+
+/// Fits a polynomial of the given degree to `(x, y)` by least squares.
+/// Returns the coefficients with the highest power first, as numpy's `polyfit` does.
+pub fn polyfit<T>(x: &Vector<T>, y: &Vector<T>, degree: usize) -> Result<Vector<T>, MathError>
+where
+  T: Float + Copy,
+{
+  check_same_len(x, y)?;
+  let (xs, ys): (Vec<T>, Vec<T>) = x
+    .iter()
+    .zip(y.iter())
+    .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+    .map(|(&a, &b)| (a, b))
+    .unzip();
+  if xs.len() <= degree {
+    return Err(MathError::InvalidArgument(format!(
+      "a degree {} polynomial requires more than {} points, found {}",
+      degree,
+      degree,
+      xs.len()
+    )));
+  }
+
+  // Vandermonde matrix with columns x^degree, ..., x^0, each scaled to unit norm
+  // to keep the least-squares problem well conditioned.
+  let mut vander = Matrix::from_fn(xs.len(), degree + 1, |i, j| xs[i].powi((degree - j) as i32));
+  let scale: Vec<T> = (0..=degree)
+    .map(|j| {
+      let norm = vander.col(j).iter().fold(T::zero(), |acc, &v| acc.hypot(v));
+      if norm == T::zero() {
+        T::one()
+      } else {
+        norm
+      }
+    })
+    .collect();
+  for i in 0..xs.len() {
+    for (j, &s) in scale.iter().enumerate() {
+      vander[(i, j)] = vander[(i, j)] / s;
+    }
+  }
+  let coef = vander.lstsq(&Vector::new(ys))?;
+  Ok(Vector::new(
+    coef
+      .iter()
+      .zip(scale.iter())
+      .map(|(&c, &s)| c / s)
+      .collect(),
+  ))
+}
+
+/// Evaluates a polynomial with coefficients `coef` (highest power first) at each element of `x`.
+pub fn polyval<T>(coef: &Vector<T>, x: &Vector<T>) -> Vector<T>
+where
+  T: Float + Copy,
+{
+  Vector::new(
+    x.iter()
+      .map(|&v| coef.iter().fold(T::zero(), |acc, &c| acc.mul_add(v, c)))
+      .collect(),
+  )
+}
@@ -0,0 +1,350 @@
+//! Robust line fitting: Theil-Sen and Siegel repeated-median estimators, and
+//! iteratively reweighted least squares with Huber or bisquare weights.
+//!
+//! Pairs where either value is NaN are dropped before fitting.
+
+use crate::error::{check_same_len, MathError};
+use crate::sort::SortOps;
+use crate::special::normal_ppf;
+use crate::statistics::Statistics;
+use crate::vector::Vector;
+use num_traits::Float;
+
+/// Maximum number of reweighting iterations in [`irls`].
+const MAX_IRLS_ITER: usize = 100;
+
+/// Consistency constant that turns the median absolute deviation into an estimate
+/// of the standard deviation for normally distributed data.
+const MAD_TO_SIGMA: f64 = 0.674_489_750_196_081_7;
+
+/// A line `y = intercept + slope * x` fitted by a robust estimator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RobustLine<T> {
+  /// Slope of the fitted line.
+  pub slope: T,
+  /// Intercept of the fitted line.
+  pub intercept: T,
+  /// Confidence interval `(lower, upper)` for the slope, when the estimator provides one.
+  pub slope_ci: Option<(T, T)>,
+}
+
+/// Weight function used by [`irls`], with its tuning constant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RobustWeight<T> {
+  /// Huber weights: 1 for scaled residuals up to `k`, `k / |u|` beyond.
+  Huber(T),
+  /// Tukey's bisquare weights: `(1 - (u / k)^2)^2` for `|u| < k`, 0 beyond.
+  Bisquare(T),
+}
+
+impl<T: Float> RobustWeight<T> {
+  /// Huber weights with `k = 1.345`, giving 95% efficiency for normal errors.
+  pub fn huber() -> Self {
+    RobustWeight::Huber(T::from(1.345).unwrap())
+  }
+
+  /// Bisquare weights with `k = 4.685`, giving 95% efficiency for normal errors.
+  pub fn bisquare() -> Self {
+    RobustWeight::Bisquare(T::from(4.685).unwrap())
+  }
+
+  /// Weight given to a residual of `u` robust standard deviations.
+  fn weight(&self, u: T) -> T {
+    let u = u.abs();
+    match *self {
+      RobustWeight::Huber(k) => {
+        if u <= k {
+          T::one()
+        } else {
+          k / u
+        }
+      }
+      RobustWeight::Bisquare(k) => {
+        if u < k {
+          let r = T::one() - (u / k) * (u / k);
+          r * r
+        } else {
+          T::zero()
+        }
+      }
+    }
+  }
+}
+
+/// Result of an [`irls`] fit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IrlsResult<T> {
+  /// Slope of the fitted line.
+  pub slope: T,
+  /// Intercept of the fitted line.
+  pub intercept: T,
+  /// Standard error of the slope from the final weighted least-squares fit, with the
+  /// residual variance estimated on `n - 2` degrees of freedom for the `n` observations used.
+  pub slope_stderr: T,
+  /// Normal-approximation confidence interval `(lower, upper)` for the slope.
+  pub slope_ci: (T, T),
+  /// Final weight of each observation used, between 0 and 1.
+  pub weights: Vector<T>,
+  /// Robust estimate of the residual standard deviation.
+  pub scale: T,
+  /// Number of reweighting iterations performed.
+  pub iterations: usize,
+}
+
+/// Fits a line with the Theil-Sen estimator: the slope is the median of the slopes
+/// between all pairs of points, and the intercept is the median of `y - slope * x`.
+///
+/// The slope confidence interval at level `confidence` (e.g. `0.95`) is based on
+/// Kendall's tau with a correction for ties, as in scipy's `theilslopes`.
+pub fn theil_sen<T>(x: &Vector<T>, y: &Vector<T>, confidence: T) -> Result<RobustLine<T>, MathError>
+where
+  T: Float + Copy,
+{
+  if !(confidence > T::zero() && confidence < T::one()) {
+    return Err(MathError::InvalidArgument(
+      "confidence must be between 0 and 1".to_string(),
+    ));
+  }
+  let (xs, ys) = complete_pairs(x, y)?;
+  let slopes = pairwise_slopes(&xs, &ys).sorted();
+  let slope = slopes.median().ok_or_else(all_x_equal)?;
+  let intercept = intercept_for(&xs, &ys, slope);
+
+  // Variance of Kendall's S statistic, corrected for ties in x and y.
+  let two = T::from(2.0).unwrap();
+  let five = T::from(5.0).unwrap();
+  let tie_term = |k: T| k * (k - T::one()) * (two * k + five);
+  let ties = |v: &[T]| -> T {
+    let sorted = Vector::new(v.to_vec()).sorted();
+    let mut total = T::zero();
+    let mut start = 0;
+    while start < sorted.len() {
+      let mut end = start + 1;
+      while end < sorted.len() && sorted[end] == sorted[start] {
+        end += 1;
+      }
+      total = total + tie_term(T::from(end - start).unwrap());
+      start = end;
+    }
+    total
+  };
+  let nt = T::from(slopes.len()).unwrap();
+  let sigma_sq =
+    (tie_term(T::from(xs.len()).unwrap()) - ties(&xs) - ties(&ys)) / T::from(18.0).unwrap();
+  let z = normal_ppf(T::one() - (T::one() - confidence) / two);
+  let spread = z * sigma_sq.max(T::zero()).sqrt();
+  let last = slopes.len() - 1;
+  let upper = ((nt + spread) / two)
+    .round()
+    .to_usize()
+    .unwrap_or(last)
+    .min(last);
+  let lower = ((nt - spread) / two)
+    .round()
+    .max(T::one())
+    .to_usize()
+    .unwrap_or(1)
+    - 1;
+
+  Ok(RobustLine {
+    slope,
+    intercept,
+    slope_ci: Some((slopes[lower.min(last)], slopes[upper])),
+  })
+}
+
+/// Fits a line with Siegel's repeated-median estimator: for each point, take the
+/// median slope to every other point, then take the median of those medians.
+/// The intercept is the median of `y - slope * x`. Tolerates up to 50% outliers.
+pub fn siegel<T>(x: &Vector<T>, y: &Vector<T>) -> Result<RobustLine<T>, MathError>
+where
+  T: Float + Copy,
+{
+  let (xs, ys) = complete_pairs(x, y)?;
+  let medians: Vector<T> = Vector::new(
+    (0..xs.len())
+      .filter_map(|i| {
+        let slopes: Vector<T> = Vector::new(
+          (0..xs.len())
+            .filter(|&j| xs[j] != xs[i])
+            .map(|j| (ys[j] - ys[i]) / (xs[j] - xs[i]))
+            .collect(),
+        );
+        slopes.median()
+      })
+      .collect(),
+  );
+  let slope = medians.median().ok_or_else(all_x_equal)?;
+  Ok(RobustLine {
+    slope,
+    intercept: intercept_for(&xs, &ys, slope),
+    slope_ci: None,
+  })
+}
+
+/// Fits a line by iteratively reweighted least squares, starting from ordinary least
+/// squares. Each iteration rescales the residuals by their median absolute deviation
+/// and downweights large ones according to `weight`.
+///
+/// The slope confidence interval at level `confidence` uses the normal approximation.
+pub fn irls<T>(
+  x: &Vector<T>,
+  y: &Vector<T>,
+  weight: RobustWeight<T>,
+  confidence: T,
+) -> Result<IrlsResult<T>, MathError>
+where
+  T: Float + Copy,
+{
+  if !(confidence > T::zero() && confidence < T::one()) {
+    return Err(MathError::InvalidArgument(
+      "confidence must be between 0 and 1".to_string(),
+    ));
+  }
+  let (xs, ys) = complete_pairs(x, y)?;
+  let n = xs.len();
+  if n < 3 {
+    return Err(MathError::InvalidArgument(
+      "robust regression requires at least three observations".to_string(),
+    ));
+  }
+
+  let mut w = vec![T::one(); n];
+  let (mut slope, mut intercept) = weighted_line(&xs, &ys, &w).ok_or_else(all_x_equal)?;
+  let mut scale = T::zero();
+  let mut iterations = 0;
+  let tol = T::epsilon().sqrt();
+  while iterations < MAX_IRLS_ITER {
+    iterations += 1;
+    let residuals: Vector<T> = Vector::new(
+      xs.iter()
+        .zip(ys.iter())
+        .map(|(&a, &b)| b - intercept - slope * a)
+        .collect(),
+    );
+    let center = residuals.median().unwrap_or(T::zero());
+    let mad = Vector::new(residuals.iter().map(|&r| (r - center).abs()).collect())
+      .median()
+      .unwrap_or(T::zero());
+    scale = mad / T::from(MAD_TO_SIGMA).unwrap();
+    if scale == T::zero() {
+      // More than half the points lie exactly on the line; it is already the robust fit.
+      break;
+    }
+    for (wi, &r) in w.iter_mut().zip(residuals.iter()) {
+      *wi = weight.weight(r / scale);
+    }
+    let (new_slope, new_intercept) = weighted_line(&xs, &ys, &w).ok_or_else(all_x_equal)?;
+    let converged = (new_slope - slope).abs() <= tol * (T::one() + slope.abs())
+      && (new_intercept - intercept).abs() <= tol * (T::one() + intercept.abs());
+    slope = new_slope;
+    intercept = new_intercept;
+    if converged {
+      break;
+    }
+  }
+
+  // Standard error of the weighted least-squares slope: s^2 / sum(w * (x - x_w)^2), with
+  // s^2 = sum(w * r^2) / (n - 2). The weights are not a sample size, so they do not set
+  // the degrees of freedom.
+  let sw = w.iter().fold(T::zero(), |acc, &v| acc + v);
+  let xw = xs
+    .iter()
+    .zip(w.iter())
+    .fold(T::zero(), |acc, (&a, &v)| acc + a * v)
+    / sw;
+  let sxx = xs
+    .iter()
+    .zip(w.iter())
+    .fold(T::zero(), |acc, (&a, &v)| acc + v * (a - xw) * (a - xw));
+  let ssr = xs
+    .iter()
+    .zip(ys.iter())
+    .zip(w.iter())
+    .fold(T::zero(), |acc, ((&a, &b), &v)| {
+      let r = b - intercept - slope * a;
+      acc + v * r * r
+    });
+  let slope_stderr = (ssr / T::from(n - 2).unwrap() / sxx).sqrt();
+  let z = normal_ppf(T::one() - (T::one() - confidence) / T::from(2.0).unwrap());
+
+  Ok(IrlsResult {
+    slope,
+    intercept,
+    slope_stderr,
+    slope_ci: (slope - z * slope_stderr, slope + z * slope_stderr),
+    weights: Vector::new(w),
+    scale,
+    iterations,
+  })
+}
+
+/// Drops pairs where either value is NaN.
+fn complete_pairs<T: Float>(x: &Vector<T>, y: &Vector<T>) -> Result<(Vec<T>, Vec<T>), MathError> {
+  check_same_len(x, y)?;
+  Ok(
+    x.iter()
+      .zip(y.iter())
+      .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+      .map(|(&a, &b)| (a, b))
+      .unzip(),
+  )
+}
+
+/// Slopes between all pairs of points with distinct `x`.
+fn pairwise_slopes<T: Float>(xs: &[T], ys: &[T]) -> Vector<T> {
+  let mut slopes = Vec::with_capacity(xs.len() * xs.len().saturating_sub(1) / 2);
+  for i in 0..xs.len() {
+    for j in i + 1..xs.len() {
+      if xs[j] != xs[i] {
+        slopes.push((ys[j] - ys[i]) / (xs[j] - xs[i]));
+      }
+    }
+  }
+  Vector::new(slopes)
+}
+
+/// Median of `y - slope * x`.
+fn intercept_for<T: Float>(xs: &[T], ys: &[T], slope: T) -> T {
+  Vector::new(
+    xs.iter()
+      .zip(ys.iter())
+      .map(|(&a, &b)| b - slope * a)
+      .collect(),
+  )
+  .median()
+  .unwrap_or(T::nan())
+}
+
+/// Weighted least-squares line, or `None` if the weighted `x` values are all equal.
+fn weighted_line<T: Float>(xs: &[T], ys: &[T], w: &[T]) -> Option<(T, T)> {
+  let sw = w.iter().fold(T::zero(), |acc, &v| acc + v);
+  if sw == T::zero() {
+    return None;
+  }
+  let xw = xs
+    .iter()
+    .zip(w)
+    .fold(T::zero(), |acc, (&a, &v)| acc + a * v)
+    / sw;
+  let yw = ys
+    .iter()
+    .zip(w)
+    .fold(T::zero(), |acc, (&b, &v)| acc + b * v)
+    / sw;
+  let (mut sxx, mut sxy) = (T::zero(), T::zero());
+  for ((&a, &b), &v) in xs.iter().zip(ys).zip(w) {
+    sxx = sxx + v * (a - xw) * (a - xw);
+    sxy = sxy + v * (a - xw) * (b - yw);
+  }
+  if sxx == T::zero() {
+    return None;
+  }
+  let slope = sxy / sxx;
+  Some((slope, yw - slope * xw))
+}
+
+/// Error for inputs where no slope can be estimated.
+fn all_x_equal() -> MathError {
+  MathError::InvalidArgument("at least two distinct x values are required".to_string())
+}
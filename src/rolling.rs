@@ -0,0 +1,453 @@
+//! Rolling-window statistics for `Vector<T>`.
+//!
+//! `rolling(window)` returns a [`Rolling`] view whose methods produce a `Vector<T>` of
+//! the same length as the input, where each element is the statistic over the window
+//! ending at (or, when centered, surrounding) that position. NaN values inside a window
+//! are ignored, and positions whose window holds fewer than `min_periods` non-NaN
+//! values are NaN.
+//!
+//! `expanding()` returns the same view with windows that grow from the start of the series.
+
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::Float;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Trait providing rolling-window views over `Vector<T>`.
+pub trait RollingOps<T> {
+  /// Returns a rolling view with windows of `window` elements.
+  /// By default `min_periods` equals `window` and windows trail their position.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `window` is zero.
+  fn rolling(&self, window: usize) -> Rolling<'_, T>;
+
+  /// Returns an expanding view, where each window runs from the start of the series to
+  /// the position, so `expanding().mean()` is the running mean. `min_periods` defaults to 1.
+  fn expanding(&self) -> Rolling<'_, T>;
+}
+
+impl<T> RollingOps<T> for Vector<T>
+where
+  T: Float + Copy + PartialOrd,
+{
+  fn rolling(&self, window: usize) -> Rolling<'_, T> {
+    assert!(window > 0, "rolling window must be at least 1");
+    Rolling {
+      data: self,
+      window,
+      min_periods: window,
+      center: false,
+    }
+  }
+
+  fn expanding(&self) -> Rolling<'_, T> {
+    Rolling {
+      data: self,
+      window: self.len().max(1),
+      min_periods: 1,
+      center: false,
+    }
+  }
+}
+
+/// A rolling-window view over a vector, created by [`RollingOps::rolling`].
+#[derive(Debug, Clone, Copy)]
+pub struct Rolling<'a, T> {
+  data: &'a [T],
+  window: usize,
+  min_periods: usize,
+  center: bool,
+}
+
+impl<'a, T> Rolling<'a, T>
+where
+  T: Float + Copy + PartialOrd,
+{
+  /// Sets the minimum number of non-NaN values a window needs to produce a result.
+  /// Values below 1 are treated as 1.
+  pub fn min_periods(mut self, min_periods: usize) -> Self {
+    self.min_periods = min_periods.max(1);
+    self
+  }
+
+  /// Centers each window on its position instead of ending it there. For an even
+  /// window the extra element is taken from before the position, as pandas does.
+  pub fn center(mut self, center: bool) -> Self {
+    self.center = center;
+    self
+  }
+
+  /// Computes the sum of each window, using compensated summation.
+  pub fn sum(&self) -> Vector<T> {
+    self.sweep(KahanSum::default(), |s, _| s.sum)
+  }
+
+  /// Computes the mean of each window.
+  pub fn mean(&self) -> Vector<T> {
+    self.sweep(Welford::default(), |s, _| s.mean)
+  }
+
+  /// Computes the variance of each window. As with `Statistics::var`, this divides by
+  /// the number of values and is NaN for windows with fewer than two values.
+  pub fn var(&self) -> Vector<T> {
+    self.sweep(Welford::default(), |s, count| {
+      if count < 2 {
+        T::nan()
+      } else {
+        s.m2.max(T::zero()) / T::from(count).unwrap()
+      }
+    })
+  }
+
+  /// Computes the standard deviation of each window, the square root of `var`.
+  pub fn stddev(&self) -> Vector<T> {
+    self
+      .var()
+      .iter()
+      .map(|v| v.sqrt())
+      .collect::<Vec<T>>()
+      .into_vector()
+  }
+
+  /// Returns the minimum of each window, in O(n) using a monotonic deque.
+  pub fn min(&self) -> Vector<T> {
+    self.sweep(MonotonicDeque::new(|a: T, b: T| a <= b), |s, _| s.front())
+  }
+
+  /// Returns the maximum of each window, in O(n) using a monotonic deque.
+  pub fn max(&self) -> Vector<T> {
+    self.sweep(MonotonicDeque::new(|a: T, b: T| a >= b), |s, _| s.front())
+  }
+
+  /// Computes the median of each window.
+  pub fn median(&self) -> Vector<T> {
+    self.quantile(T::from(0.5).unwrap())
+  }
+
+  /// Computes the quantile `q` of each window, interpolating linearly between order
+  /// statistics as `Statistics::quantile` does. Runs in O(n log w) using two heaps that
+  /// split each window at the quantile's rank. Every position is NaN if `q` is outside
+  /// `[0, 1]`.
+  pub fn quantile(&self, q: T) -> Vector<T> {
+    if !(q >= T::zero() && q <= T::one()) {
+      return vec![T::nan(); self.data.len()].into_vector();
+    }
+    // `sweep` pushes a window's newest value before popping the oldest one.
+    let span = self.window.min(self.data.len()) + 1;
+    self.sweep(WindowQuantile::new(span, q), |s, _| s.value())
+  }
+
+  /// Slides the window over the data, keeping `state` in sync with the non-NaN values
+  /// it holds, and evaluates `value` wherever the window has enough values.
+  fn sweep<S, F>(&self, mut state: S, value: F) -> Vector<T>
+  where
+    S: WindowState<T>,
+    F: Fn(&S, usize) -> T,
+  {
+    let n = self.data.len();
+    let offset = if self.center {
+      (self.window - 1) / 2
+    } else {
+      0
+    };
+    let mut result = Vec::with_capacity(n);
+    let (mut start, mut end, mut count) = (0, 0, 0);
+    for i in 0..n {
+      let new_end = (i + 1 + offset).min(n);
+      let new_start = (i + 1 + offset).saturating_sub(self.window);
+      while end < new_end {
+        let x = self.data[end];
+        if !x.is_nan() {
+          state.push(end, x);
+          count += 1;
+        }
+        end += 1;
+      }
+      while start < new_start {
+        let x = self.data[start];
+        if !x.is_nan() {
+          state.pop(start, x);
+          count -= 1;
+        }
+        start += 1;
+      }
+      if count >= self.min_periods {
+        result.push(value(&state, count));
+      } else {
+        result.push(T::nan());
+      }
+    }
+    result.into_vector()
+  }
+}
+
+/// Incrementally maintained statistic over the non-NaN values of a sliding window.
+/// Values are pushed and popped in index order.
+trait WindowState<T> {
+  fn push(&mut self, index: usize, x: T);
+  fn pop(&mut self, index: usize, x: T);
+}
+
+/// Running sum with Kahan compensation, so that long series do not drift.
+struct KahanSum<T> {
+  sum: T,
+  compensation: T,
+}
+
+impl<T: Float> Default for KahanSum<T> {
+  fn default() -> Self {
+    KahanSum {
+      sum: T::zero(),
+      compensation: T::zero(),
+    }
+  }
+}
+
+impl<T: Float> KahanSum<T> {
+  fn add(&mut self, x: T) {
+    let y = x - self.compensation;
+    let t = self.sum + y;
+    self.compensation = (t - self.sum) - y;
+    self.sum = t;
+  }
+}
+
+impl<T: Float> WindowState<T> for KahanSum<T> {
+  fn push(&mut self, _: usize, x: T) {
+    self.add(x);
+  }
+
+  fn pop(&mut self, _: usize, x: T) {
+    self.add(-x);
+  }
+}
+
+/// Welford's running mean and sum of squared deviations, with removal.
+struct Welford<T> {
+  count: usize,
+  mean: T,
+  m2: T,
+}
+
+impl<T: Float> Default for Welford<T> {
+  fn default() -> Self {
+    Welford {
+      count: 0,
+      mean: T::zero(),
+      m2: T::zero(),
+    }
+  }
+}
+
+impl<T: Float> WindowState<T> for Welford<T> {
+  fn push(&mut self, _: usize, x: T) {
+    self.count += 1;
+    let delta = x - self.mean;
+    self.mean = self.mean + delta / T::from(self.count).unwrap();
+    self.m2 = self.m2 + delta * (x - self.mean);
+  }
+
+  fn pop(&mut self, _: usize, x: T) {
+    self.count -= 1;
+    if self.count == 0 {
+      *self = Welford::default();
+      return;
+    }
+    let delta = x - self.mean;
+    self.mean = self.mean - delta / T::from(self.count).unwrap();
+    self.m2 = self.m2 - delta * (x - self.mean);
+  }
+}
+
+/// Deque of `(index, value)` pairs whose values are monotonic under `keep`, so the
+/// front is the window's extreme value.
+struct MonotonicDeque<T, F> {
+  deque: VecDeque<(usize, T)>,
+  keep: F,
+}
+
+impl<T: Float, F: Fn(T, T) -> bool> MonotonicDeque<T, F> {
+  fn new(keep: F) -> Self {
+    MonotonicDeque {
+      deque: VecDeque::new(),
+      keep,
+    }
+  }
+
+  fn front(&self) -> T {
+    self.deque.front().map_or(T::nan(), |&(_, v)| v)
+  }
+}
+
+impl<T: Float, F: Fn(T, T) -> bool> WindowState<T> for MonotonicDeque<T, F> {
+  fn push(&mut self, index: usize, x: T) {
+    while let Some(&(_, back)) = self.deque.back() {
+      if (self.keep)(back, x) {
+        break;
+      }
+      self.deque.pop_back();
+    }
+    self.deque.push_back((index, x));
+  }
+
+  fn pop(&mut self, index: usize, _: T) {
+    if self.deque.front().is_some_and(|&(i, _)| i == index) {
+      self.deque.pop_front();
+    }
+  }
+}
+
+/// Window value tagged with its index, ordered by value and then by index so that
+/// heap entries are distinct.
+struct Ranked<T>(T, usize);
+
+impl<T: Float> PartialEq for Ranked<T> {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl<T: Float> Eq for Ranked<T> {}
+
+impl<T: Float> PartialOrd for Ranked<T> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<T: Float> Ord for Ranked<T> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    // Window values are never NaN.
+    self
+      .0
+      .partial_cmp(&other.0)
+      .unwrap()
+      .then(self.1.cmp(&other.1))
+  }
+}
+
+/// Quantile of the window values, kept as a max-heap `low` holding the values up to the
+/// lower order statistic of the quantile and a min-heap `high` holding the rest.
+///
+/// Popped values are removed lazily: they stay in their heap until they reach the top,
+/// and a heap is rebuilt once stale entries outnumber live ones, so both heaps stay
+/// within a constant factor of the window size.
+struct WindowQuantile<T> {
+  q: T,
+  low: BinaryHeap<Ranked<T>>,
+  high: BinaryHeap<Reverse<Ranked<T>>>,
+  low_len: usize,
+  high_len: usize,
+  /// Whether each live index is in `low`, stored at `index % in_low.len()`.
+  in_low: Vec<bool>,
+  /// Every index below this has been popped.
+  popped: usize,
+}
+
+impl<T: Float> WindowQuantile<T> {
+  /// Creates an empty state for windows spanning at most `span` indices.
+  fn new(span: usize, q: T) -> Self {
+    WindowQuantile {
+      q,
+      low: BinaryHeap::new(),
+      high: BinaryHeap::new(),
+      low_len: 0,
+      high_len: 0,
+      in_low: vec![false; span],
+      popped: 0,
+    }
+  }
+
+  /// Position of the quantile among the sorted window values.
+  fn position(&self) -> T {
+    self.q * T::from(self.low_len + self.high_len - 1).unwrap()
+  }
+
+  /// Returns the quantile of the window values, which must not be empty.
+  fn value(&self) -> T {
+    let pos = self.position();
+    let weight = pos - pos.floor();
+    let below = self.low.peek().unwrap().0;
+    if weight == T::zero() {
+      below
+    } else {
+      below + (self.high.peek().unwrap().0 .0 - below) * weight
+    }
+  }
+
+  /// Drops popped entries from the top of both heaps, and rebuilds a heap that holds
+  /// more stale entries than live ones.
+  fn prune(&mut self) {
+    let popped = self.popped;
+    while self.low.peek().is_some_and(|e| e.1 < popped) {
+      self.low.pop();
+    }
+    while self.high.peek().is_some_and(|e| e.0 .1 < popped) {
+      self.high.pop();
+    }
+    if self.low.len() > 2 * self.low_len + 16 {
+      self.low.retain(|e| e.1 >= popped);
+    }
+    if self.high.len() > 2 * self.high_len + 16 {
+      self.high.retain(|e| e.0 .1 >= popped);
+    }
+  }
+
+  /// Moves values between the heaps until `low` holds exactly the values up to the lower
+  /// order statistic of the quantile.
+  fn rebalance(&mut self) {
+    self.prune();
+    let count = self.low_len + self.high_len;
+    let target = if count == 0 {
+      0
+    } else {
+      self.position().floor().to_usize().unwrap() + 1
+    };
+    let span = self.in_low.len();
+    while self.low_len > target {
+      let entry = self.low.pop().unwrap();
+      self.in_low[entry.1 % span] = false;
+      self.high.push(Reverse(entry));
+      self.low_len -= 1;
+      self.high_len += 1;
+      self.prune();
+    }
+    while self.low_len < target {
+      let Reverse(entry) = self.high.pop().unwrap();
+      self.in_low[entry.1 % span] = true;
+      self.low.push(entry);
+      self.high_len -= 1;
+      self.low_len += 1;
+      self.prune();
+    }
+  }
+}
+
+impl<T: Float> WindowState<T> for WindowQuantile<T> {
+  fn push(&mut self, index: usize, x: T) {
+    let span = self.in_low.len();
+    let to_low = self.low.peek().is_some_and(|e| x <= e.0);
+    self.in_low[index % span] = to_low;
+    if to_low {
+      self.low.push(Ranked(x, index));
+      self.low_len += 1;
+    } else {
+      self.high.push(Reverse(Ranked(x, index)));
+      self.high_len += 1;
+    }
+    self.rebalance();
+  }
+
+  fn pop(&mut self, index: usize, _: T) {
+    if self.in_low[index % self.in_low.len()] {
+      self.low_len -= 1;
+    } else {
+      self.high_len -= 1;
+    }
+    self.popped = index + 1;
+    self.rebalance();
+  }
+}
@@ -0,0 +1,123 @@
+//! Differencing, shifting and percentage change for `Vector<T>` treated as an ordered series.
+//!
+//! Results keep the length of the input. Positions that have no earlier value to compare
+//! against are NaN, so the results can be passed straight to `Statistics`, which ignores NaN.
+
+use crate::error::MathError;
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::Float;
+
+/// Trait providing lag-based series transformations for `Vector<T>`.
+pub trait SeriesOps<T> {
+  /// Computes the `lag`-period difference `x[i] - x[i - lag]`, applied `order` times.
+  /// The first `lag * order` positions are NaN. An `order` of zero returns a copy.
+  fn diff(&self, lag: usize, order: usize) -> Vector<T>;
+
+  /// Rebuilds a series from its differences, inverting `diff(lag, order)`.
+  ///
+  /// `self` is the output of `diff`, whose leading NaN positions are ignored, and
+  /// `initial` holds the first `lag * order` values of the original series.
+  /// Returns an error if `initial` has the wrong length, or if `lag` is zero while
+  /// `order` is not.
+  fn undiff(&self, initial: &Vector<T>, lag: usize, order: usize) -> Result<Vector<T>, MathError>;
+
+  /// Shifts the values by `n` positions, towards the end for positive `n` and towards the
+  /// start for negative `n`. Vacated positions are set to `fill`, typically NaN.
+  fn shift(&self, n: isize, fill: T) -> Vector<T>;
+
+  /// Computes the relative change `x[i] / x[i - periods] - 1`.
+  /// The first `periods` positions are NaN.
+  fn pct_change(&self, periods: usize) -> Vector<T>;
+
+  /// Computes the logarithmic returns `ln(x[i] / x[i - 1])`. The first position is NaN.
+  fn log_returns(&self) -> Vector<T>;
+}
+
+impl<T> SeriesOps<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn diff(&self, lag: usize, order: usize) -> Vector<T> {
+    let mut result = self.to_vec();
+    for _ in 0..order {
+      result = lagged(&result, lag, |cur, prev| cur - prev);
+    }
+    result.into_vector()
+  }
+
+  fn undiff(&self, initial: &Vector<T>, lag: usize, order: usize) -> Result<Vector<T>, MathError> {
+    if order == 0 {
+      return Ok(self.clone());
+    }
+    if lag == 0 {
+      return Err(MathError::InvalidArgument(
+        "a zero-lag difference cannot be inverted".to_string(),
+      ));
+    }
+    let known = lag * order;
+    if initial.len() != known {
+      return Err(MathError::InvalidLength {
+        expected: known,
+        found: initial.len(),
+      });
+    }
+    let n = self.len().max(known);
+    // levels[k] is the k-th difference of the series. The differences of `initial`
+    // supply each level's values before position `known`.
+    let mut levels: Vec<Vec<T>> = (0..order)
+      .map(|k| {
+        let mut level = vec![T::nan(); n];
+        level[..known].copy_from_slice(&initial.diff(lag, k)[..]);
+        level
+      })
+      .collect();
+    let mut current: Vec<T> = (0..n)
+      .map(|i| if i < known { T::nan() } else { self[i] })
+      .collect();
+    for level in levels.iter_mut().rev() {
+      for i in known..n {
+        level[i] = current[i] + level[i - lag];
+      }
+      current = std::mem::take(level);
+    }
+    Ok(current.into_vector())
+  }
+
+  fn shift(&self, n: isize, fill: T) -> Vector<T> {
+    let len = self.len();
+    let k = n.unsigned_abs().min(len);
+    let mut result = vec![fill; len];
+    if n >= 0 {
+      result[k..].copy_from_slice(&self[..len - k]);
+    } else {
+      result[..len - k].copy_from_slice(&self[k..]);
+    }
+    result.into_vector()
+  }
+
+  fn pct_change(&self, periods: usize) -> Vector<T> {
+    lagged(self, periods, |cur, prev| cur / prev - T::one()).into_vector()
+  }
+
+  fn log_returns(&self) -> Vector<T> {
+    lagged(self, 1, |cur, prev| (cur / prev).ln()).into_vector()
+  }
+}
+
+/// Applies `f(x[i], x[i - lag])` at each position, with NaN for the first `lag` positions.
+fn lagged<T, F>(x: &[T], lag: usize, f: F) -> Vec<T>
+where
+  T: Float,
+  F: Fn(T, T) -> T,
+{
+  (0..x.len())
+    .map(|i| {
+      if i < lag {
+        T::nan()
+      } else {
+        f(x[i], x[i - lag])
+      }
+    })
+    .collect()
+}
@@ -0,0 +1,335 @@
+//! Smoothing filters for `Vector<T>`: moving average, Savitzky-Golay, median, Gaussian
+//! and LOWESS.
+//!
+//! Every filter returns a `Vector<T>` of the input length. How each one treats the
+//! edges, where a full window is not available, is documented on the method.
+
+use crate::error::{check_same_len, MathError};
+use crate::matrix::Matrix;
+use crate::regression::{polyfit, polyval};
+use crate::rolling::RollingOps;
+use crate::statistics::Statistics;
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::Float;
+
+/// Kernels of [`Smoothing::gaussian_smooth`] extend this many standard deviations.
+const GAUSSIAN_TRUNCATE: f64 = 4.0;
+
+/// Trait providing smoothing filters for `Vector<T>`.
+pub trait Smoothing<T> {
+  /// Replaces each value with the mean of the centered window of `window` values.
+  /// NaN values are skipped, and windows shrink at the edges to the values available.
+  /// Returns an error if `window` is zero.
+  fn moving_average(&self, window: usize) -> Result<Vector<T>, MathError>;
+
+  /// Applies a Savitzky-Golay filter: each value is replaced by a least-squares
+  /// polynomial of degree `polyorder` fitted to the centered window of `window` values.
+  /// At the edges, the polynomial fitted to the first or last full window is evaluated,
+  /// as scipy's `savgol_filter` does with `mode="interp"`.
+  /// Returns an error unless `window` is odd, greater than `polyorder` and at most the
+  /// vector length.
+  fn savgol(&self, window: usize, polyorder: usize) -> Result<Vector<T>, MathError>;
+
+  /// Replaces each value with the median of the centered window of `window` values.
+  /// NaN values are skipped, and windows shrink at the edges to the values available.
+  /// Returns an error if `window` is zero.
+  fn median_filter(&self, window: usize) -> Result<Vector<T>, MathError>;
+
+  /// Convolves with a Gaussian kernel of standard deviation `sigma` samples, truncated
+  /// at four standard deviations. The input is mirrored at the edges (`d c b a | a b c d`),
+  /// as scipy's `gaussian_filter1d` does by default. NaN values propagate.
+  /// Returns an error if `sigma` is not positive, or so large that the kernel radius
+  /// overflows.
+  fn gaussian_smooth(&self, sigma: T) -> Result<Vector<T>, MathError>;
+
+  /// Applies LOWESS with the positions `0, 1, 2, ...` as `x`. See [`lowess`].
+  fn lowess(&self, frac: T, iterations: usize) -> Result<Vector<T>, MathError>;
+}
+
+impl<T> Smoothing<T> for Vector<T>
+where
+  T: Float + Copy,
+{
+  fn moving_average(&self, window: usize) -> Result<Vector<T>, MathError> {
+    check_window(window)?;
+    Ok(self.rolling(window).center(true).min_periods(1).mean())
+  }
+
+  fn savgol(&self, window: usize, polyorder: usize) -> Result<Vector<T>, MathError> {
+    let n = self.len();
+    if window % 2 == 0 || window <= polyorder || window > n {
+      return Err(MathError::InvalidArgument(format!(
+        "savgol window must be odd, greater than polyorder ({}) and at most {}, found {}",
+        polyorder, n, window
+      )));
+    }
+    let half = window / 2;
+    let coef = savgol_coefficients::<T>(half, polyorder)?;
+    let mut result: Vec<T> = (0..n)
+      .map(|i| {
+        if i < half || i + half >= n {
+          T::nan()
+        } else {
+          coef
+            .iter()
+            .zip(&self[i - half..=i + half])
+            .fold(T::zero(), |acc, (&c, &x)| acc + c * x)
+        }
+      })
+      .collect();
+
+    let positions = (0..window)
+      .map(|i| T::from(i).unwrap())
+      .collect::<Vec<T>>()
+      .into_vector();
+    for (start, range) in [(0, 0..half), (n - window, n - half..n)] {
+      let fit = polyfit(
+        &positions,
+        &self[start..start + window].to_vec().into_vector(),
+        polyorder,
+      )?;
+      let at = range
+        .clone()
+        .map(|i| T::from(i - start).unwrap())
+        .collect::<Vec<T>>()
+        .into_vector();
+      for (i, v) in range.zip(polyval(&fit, &at).iter()) {
+        result[i] = *v;
+      }
+    }
+    Ok(result.into_vector())
+  }
+
+  fn median_filter(&self, window: usize) -> Result<Vector<T>, MathError> {
+    check_window(window)?;
+    Ok(self.rolling(window).center(true).min_periods(1).median())
+  }
+
+  fn gaussian_smooth(&self, sigma: T) -> Result<Vector<T>, MathError> {
+    if sigma.is_nan() || sigma <= T::zero() {
+      return Err(MathError::InvalidArgument(
+        "sigma must be positive".to_string(),
+      ));
+    }
+    let n = self.len();
+    if n == 0 {
+      return Ok(self.clone());
+    }
+    let radius = (sigma * T::from(GAUSSIAN_TRUNCATE).unwrap() + T::from(0.5).unwrap())
+      .to_isize()
+      .ok_or_else(|| MathError::InvalidArgument("sigma is too large".to_string()))?;
+    // The mirrored input repeats with period 2n, so a kernel wider than that is folded onto
+    // one period: each offset then carries the summed weight of every position it
+    // revisits, as scipy weights them.
+    let period = 2 * n as isize;
+    let (span, shift) = if radius < n as isize {
+      (2 * radius + 1, radius)
+    } else {
+      (period, 0)
+    };
+    let mut weights = vec![T::zero(); span as usize];
+    let mut total = T::zero();
+    for j in 0..=radius {
+      let d = T::from(j).unwrap();
+      let w = (-(d * d) / (T::from(2.0).unwrap() * sigma * sigma)).exp();
+      let mut add = |offset: isize| {
+        let slot = (offset + shift).rem_euclid(span) as usize;
+        weights[slot] = weights[slot] + w;
+        total = total + w;
+      };
+      add(j);
+      if j > 0 {
+        add(-j);
+      }
+    }
+
+    // Index into the input mirrored about its edges, with period 2n.
+    let reflect = |i: isize| -> usize {
+      let j = i.rem_euclid(period) as usize;
+      if j < n {
+        j
+      } else {
+        period as usize - 1 - j
+      }
+    };
+    Ok(
+      (0..n)
+        .map(|i| {
+          weights.iter().enumerate().fold(T::zero(), |acc, (k, &w)| {
+            acc + w * self[reflect(i as isize + k as isize - shift)]
+          }) / total
+        })
+        .collect::<Vec<T>>()
+        .into_vector(),
+    )
+  }
+
+  fn lowess(&self, frac: T, iterations: usize) -> Result<Vector<T>, MathError> {
+    let x = (0..self.len())
+      .map(|i| T::from(i).unwrap())
+      .collect::<Vec<T>>()
+      .into_vector();
+    lowess(&x, self, frac, iterations)
+  }
+}
+
+/// Fits a LOWESS (locally weighted scatterplot smoothing) curve to `(x, y)` and returns
+/// the smoothed value at each `x`.
+///
+/// Each fitted value is a weighted linear regression over the `ceil(frac * n)` points
+/// nearest in `x`, with tricube weights. Each of the `iterations` robustness passes then
+/// refits with bisquare weights that downweight points with large residuals, as in
+/// Cleveland's algorithm; 3 is the usual choice. Near the edges the neighbourhood is
+/// one-sided. Pairs where either value is NaN are left out of the fits, and their
+/// positions are NaN in the result.
+///
+/// Returns an error if the lengths differ or `frac` is not in `(0, 1]`.
+pub fn lowess<T>(
+  x: &Vector<T>,
+  y: &Vector<T>,
+  frac: T,
+  iterations: usize,
+) -> Result<Vector<T>, MathError>
+where
+  T: Float + Copy,
+{
+  check_same_len(x, y)?;
+  if !(frac > T::zero() && frac <= T::one()) {
+    return Err(MathError::InvalidArgument(
+      "frac must be in (0, 1]".to_string(),
+    ));
+  }
+  let mut order: Vec<usize> = (0..x.len())
+    .filter(|&i| !x[i].is_nan() && !y[i].is_nan())
+    .collect();
+  order.sort_by(|&a, &b| x[a].partial_cmp(&x[b]).unwrap());
+  let xs: Vec<T> = order.iter().map(|&i| x[i]).collect();
+  let ys: Vec<T> = order.iter().map(|&i| y[i]).collect();
+  let n = xs.len();
+  let mut result = vec![T::nan(); x.len()];
+  if n == 0 {
+    return Ok(result.into_vector());
+  }
+  let k = (frac * T::from(n).unwrap())
+    .ceil()
+    .to_usize()
+    .unwrap_or(n)
+    .clamp(1, n);
+
+  let mut robustness = vec![T::one(); n];
+  let mut fitted = vec![T::zero(); n];
+  for pass in 0..=iterations {
+    // The k nearest neighbours of a point in sorted x form a window [lo, lo + k)
+    // that only moves right as the point does.
+    let mut lo = 0;
+    for i in 0..n {
+      while lo + k < n && xs[i] - xs[lo] > xs[lo + k] - xs[i] {
+        lo += 1;
+      }
+      let window = lo..lo + k;
+      let radius = (xs[i] - xs[lo]).max(xs[lo + k - 1] - xs[i]);
+      let weights: Vec<T> = window
+        .clone()
+        .map(|j| {
+          let w = if radius > T::zero() {
+            let u = ((xs[j] - xs[i]) / radius).abs();
+            if u < T::one() {
+              let t = T::one() - u * u * u;
+              t * t * t
+            } else {
+              T::zero()
+            }
+          } else {
+            T::one()
+          };
+          w * robustness[j]
+        })
+        .collect();
+      fitted[i] = local_linear(&xs[window.clone()], &ys[window], &weights, xs[i]);
+    }
+
+    if pass == iterations {
+      break;
+    }
+    let residuals: Vec<T> = ys
+      .iter()
+      .zip(&fitted)
+      .map(|(&a, &b)| (a - b).abs())
+      .collect();
+    let median = Vector::new(residuals.clone()).median().unwrap_or(T::zero());
+    if median == T::zero() {
+      break;
+    }
+    let cutoff = T::from(6.0).unwrap() * median;
+    for (r, &e) in robustness.iter_mut().zip(&residuals) {
+      let u = e / cutoff;
+      *r = if u < T::one() {
+        let t = T::one() - u * u;
+        t * t
+      } else {
+        T::zero()
+      };
+    }
+  }
+
+  for (pos, &i) in order.iter().enumerate() {
+    result[i] = fitted[pos];
+  }
+  Ok(result.into_vector())
+}
+
+/// Returns an error for an empty window.
+fn check_window(window: usize) -> Result<(), MathError> {
+  if window == 0 {
+    return Err(MathError::InvalidArgument(
+      "window must be at least 1".to_string(),
+    ));
+  }
+  Ok(())
+}
+
+/// Convolution weights giving the value at the centre of a degree-`polyorder`
+/// least-squares polynomial through a window of `2 * half + 1` points. They are the
+/// first row of the pseudo-inverse `(V^T V)^-1 V^T` of the Vandermonde matrix `V`.
+fn savgol_coefficients<T: Float + Copy>(
+  half: usize,
+  polyorder: usize,
+) -> Result<Vec<T>, MathError> {
+  let window = 2 * half + 1;
+  let vander = Matrix::from_fn(window, polyorder + 1, |i, j| {
+    (T::from(i).unwrap() - T::from(half).unwrap()).powi(j as i32)
+  });
+  let gram = vander.transpose().matmul(&vander)?;
+  let mut unit = vec![T::zero(); polyorder + 1];
+  unit[0] = T::one();
+  let z = gram.solve(&unit.into_vector())?;
+  Ok(vander.matvec(&z)?.to_vec())
+}
+
+/// Weighted linear regression through `(xs, ys)`, evaluated at `at`. Falls back to the
+/// weighted mean when the weighted `x` values do not vary.
+fn local_linear<T: Float>(xs: &[T], ys: &[T], weights: &[T], at: T) -> T {
+  let sw = weights.iter().fold(T::zero(), |acc, &w| acc + w);
+  if sw == T::zero() {
+    return T::nan();
+  }
+  let mut xm = T::zero();
+  let mut ym = T::zero();
+  for ((&a, &b), &w) in xs.iter().zip(ys).zip(weights) {
+    xm = xm + w * a;
+    ym = ym + w * b;
+  }
+  xm = xm / sw;
+  ym = ym / sw;
+  let (mut sxx, mut sxy) = (T::zero(), T::zero());
+  for ((&a, &b), &w) in xs.iter().zip(ys).zip(weights) {
+    sxx = sxx + w * (a - xm) * (a - xm);
+    sxy = sxy + w * (a - xm) * (b - ym);
+  }
+  if sxx == T::zero() {
+    ym
+  } else {
+    ym + sxy / sxx * (at - xm)
+  }
+}
@@ -0,0 +1,138 @@
+//! Power spectral density estimates for `Vector<T>`, following scipy's `periodogram`
+//! and `welch` with their default constant detrending and density scaling.
+
+use crate::error::MathError;
+use crate::fft::FftOps;
+use crate::vector::Vector;
+use crate::IntoVector;
+use num_traits::{Float, FloatConst};
+
+/// A one-sided power spectral density estimate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Psd<T> {
+  /// Sample frequencies, from 0 up to the Nyquist frequency `fs / 2`.
+  pub frequencies: Vector<T>,
+  /// Power spectral density at each frequency, in units squared per unit of `fs`.
+  pub power: Vector<T>,
+}
+
+/// Trait providing power spectral density estimates for a signal sampled at rate `fs`.
+pub trait Spectral<T> {
+  /// Computes the periodogram of the mean-removed signal.
+  /// Returns an error if the signal is empty or `fs` is not positive.
+  fn periodogram(&self, fs: T) -> Result<Psd<T>, MathError>;
+
+  /// Computes Welch's estimate: the average periodogram of overlapping segments of
+  /// `segment_len` samples, each mean-removed and tapered with a Hann window. Segments
+  /// start every `segment_len - overlap` samples, and a `segment_len` longer than the
+  /// signal is shortened to the signal length.
+  /// Returns an error if the signal is empty, `fs` is not positive, `segment_len` is zero
+  /// or `overlap` is not less than `segment_len`.
+  fn welch(&self, fs: T, segment_len: usize, overlap: usize) -> Result<Psd<T>, MathError>;
+}
+
+impl<T> Spectral<T> for Vector<T>
+where
+  T: Float + FloatConst,
+{
+  fn periodogram(&self, fs: T) -> Result<Psd<T>, MathError> {
+    check_signal(self, fs)?;
+    let window = vec![T::one(); self.len()];
+    Ok(Psd {
+      frequencies: frequencies(self.len(), fs),
+      power: segment_psd(self, &window, fs).into_vector(),
+    })
+  }
+
+  fn welch(&self, fs: T, segment_len: usize, overlap: usize) -> Result<Psd<T>, MathError> {
+    check_signal(self, fs)?;
+    if segment_len == 0 || overlap >= segment_len {
+      return Err(MathError::InvalidArgument(format!(
+        "segment_len must be positive and exceed overlap, found {} and {}",
+        segment_len, overlap
+      )));
+    }
+    let len = segment_len.min(self.len());
+    let step = len - overlap.min(len - 1);
+    let window = hann(len);
+    let mut power = vec![T::zero(); len / 2 + 1];
+    let mut segments = 0;
+    for start in (0..=self.len() - len).step_by(step) {
+      let psd = segment_psd(&self[start..start + len], &window, fs);
+      for (p, v) in power.iter_mut().zip(psd) {
+        *p = *p + v;
+      }
+      segments += 1;
+    }
+    let count = T::from(segments).unwrap();
+    Ok(Psd {
+      frequencies: frequencies(len, fs),
+      power: power
+        .into_iter()
+        .map(|p| p / count)
+        .collect::<Vec<T>>()
+        .into_vector(),
+    })
+  }
+}
+
+/// Validates the signal and sampling rate.
+fn check_signal<T: Float>(x: &[T], fs: T) -> Result<(), MathError> {
+  if x.is_empty() {
+    return Err(MathError::InvalidArgument(
+      "the signal is empty".to_string(),
+    ));
+  }
+  if fs.is_nan() || fs <= T::zero() {
+    return Err(MathError::InvalidArgument(
+      "the sampling rate must be positive".to_string(),
+    ));
+  }
+  Ok(())
+}
+
+/// Frequencies of the one-sided spectrum of an `n`-point transform.
+fn frequencies<T: Float>(n: usize, fs: T) -> Vector<T> {
+  let nt = T::from(n).unwrap();
+  (0..=n / 2)
+    .map(|k| T::from(k).unwrap() * fs / nt)
+    .collect::<Vec<T>>()
+    .into_vector()
+}
+
+/// Periodic Hann window of length `n`, as used for spectral estimation.
+fn hann<T: Float + FloatConst>(n: usize) -> Vec<T> {
+  let nt = T::from(n).unwrap();
+  let half = T::from(0.5).unwrap();
+  (0..n)
+    .map(|i| half - half * (T::TAU() * T::from(i).unwrap() / nt).cos())
+    .collect()
+}
+
+/// One-sided density-scaled periodogram of a mean-removed, windowed segment.
+fn segment_psd<T: Float + FloatConst>(segment: &[T], window: &[T], fs: T) -> Vec<T> {
+  let n = segment.len();
+  let mean = segment.iter().fold(T::zero(), |acc, &v| acc + v) / T::from(n).unwrap();
+  let tapered: Vector<T> = segment
+    .iter()
+    .zip(window)
+    .map(|(&x, &w)| (x - mean) * w)
+    .collect::<Vec<T>>()
+    .into_vector();
+  let scale = fs * window.iter().fold(T::zero(), |acc, &w| acc + w * w);
+  let two = T::from(2.0).unwrap();
+  tapered
+    .rfft()
+    .iter()
+    .enumerate()
+    .map(|(k, z)| {
+      // Every bin except DC and, for even n, Nyquist stands for two conjugate bins.
+      let p = z.norm_sqr() / scale;
+      if k == 0 || (n % 2 == 0 && k == n / 2) {
+        p
+      } else {
+        p * two
+      }
+    })
+    .collect()
+}
@@ -4,6 +4,36 @@ use crate::vector::Vector;
 use num_traits::{Float, ToPrimitive};
 use crate::IntoVector;
 
+/// How a quantile is chosen between the order statistics of the data, following the
+/// methods of numpy's `quantile` (Hyndman and Fan's types 1 to 9).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantileMethod {
+  /// Linear interpolation at position `(n - 1) q`. The default (type 7).
+  Linear,
+  /// The lower of the two surrounding values under `Linear`.
+  Lower,
+  /// The higher of the two surrounding values under `Linear`.
+  Higher,
+  /// The nearer of the two surrounding values under `Linear`, ties going to the even index.
+  Nearest,
+  /// The average of the two surrounding values under `Linear`.
+  Midpoint,
+  /// The smallest value whose empirical CDF is at least `q` (type 1).
+  InvertedCdf,
+  /// Like `InvertedCdf`, but averaging at the jumps of the empirical CDF (type 2).
+  AveragedInvertedCdf,
+  /// Linear interpolation of the empirical CDF (type 4).
+  InterpolatedInvertedCdf,
+  /// Interpolation with plotting positions `(k - 1/2) / n` (type 5).
+  Hazen,
+  /// Interpolation with plotting positions `k / (n + 1)` (type 6).
+  Weibull,
+  /// Approximately median-unbiased whatever the distribution (type 8).
+  MedianUnbiased,
+  /// Approximately unbiased for normally distributed data (type 9).
+  NormalUnbiased,
+}
+
 /// Trait definition for Statistics, generic over type T.
 /// T is expected to be a floating-point type like f32 or f64.
 pub trait Statistics<T> {
@@ -32,6 +62,11 @@ pub trait Statistics<T> {
   /// For example, q = 0.5 gives the median, q = 0.25 gives the 25th percentile.
   fn quantile(&self, q: T) -> Option<T>;
 
+  /// Computes the quantile for the given fraction `q` using the given method.
+  /// Returns None for an empty dataset or `q` outside [0, 1].
+  /// `QuantileMethod::Linear` gives the same result as `quantile`.
+  fn quantile_with(&self, q: T, method: QuantileMethod) -> Option<T>;
+
   /// Computes the interquartile range (IQR) of the data.
   /// Returns an Option<T>, where None represents an empty dataset.
   /// IQR is the range between the 25th percentile and 75th percentile.
@@ -49,6 +84,21 @@ pub trait Statistics<T> {
   /// Returns a `Vector<T>`, where each element is the cumulative sum up to that index.
   /// NaN values are ignored in the summation.
   fn cumsum(&self) -> Vector<T>;
+
+  /// Computes the cumulative product of the data.
+  /// Returns a `Vector<T>`, where each element is the product of all values up to that index.
+  /// NaN values are ignored in the product.
+  fn cumprod(&self) -> Vector<T>;
+
+  /// Computes the cumulative minimum of the data.
+  /// Returns a `Vector<T>`, where each element is the smallest value up to that index.
+  /// NaN values are ignored; positions before the first non-NaN value are NaN.
+  fn cummin(&self) -> Vector<T>;
+
+  /// Computes the cumulative maximum of the data.
+  /// Returns a `Vector<T>`, where each element is the largest value up to that index.
+  /// NaN values are ignored; positions before the first non-NaN value are NaN.
+  fn cummax(&self) -> Vector<T>;
 }
 
 impl<T> Statistics<T> for Vector<T>
@@ -108,29 +158,13 @@ where
   }
 
   fn quantile(&self, q: T) -> Option<T> {
-    if q < T::zero() || q > T::one() {
-      return None;
-    }
+    self.quantile_with(q, QuantileMethod::Linear)
+  }
+
+  fn quantile_with(&self, q: T, method: QuantileMethod) -> Option<T> {
     let mut non_nan_values: Vec<T> = self.iter().cloned().filter(|x| !x.is_nan()).collect();
-    let n = non_nan_values.len();
-    if n == 0 {
-      return None;
-    }
     non_nan_values.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    let pos = q * T::from(n - 1).unwrap();
-    let pos_floor = pos.floor();
-    let pos_ceil = pos.ceil();
-    let weight = pos - pos_floor;
-    let idx_floor = pos_floor.to_usize()?;
-    let idx_ceil = pos_ceil.to_usize()?;
-    if idx_floor == idx_ceil {
-      Some(non_nan_values[idx_floor])
-    } else {
-      Some(
-        non_nan_values[idx_floor]
-          + (non_nan_values[idx_ceil] - non_nan_values[idx_floor]) * weight,
-      )
-    }
+    quantile_sorted(&non_nan_values, q, method)
   }
 
   fn iqr(&self) -> Option<T> {
@@ -164,4 +198,100 @@ where
     }
     result.into_vector()
   }
+
+  fn cumprod(&self) -> Vector<T> {
+    let mut cum_prod = T::one();
+    let mut result = Vec::with_capacity(self.len());
+    for &x in self.iter() {
+      if !x.is_nan() {
+        cum_prod = cum_prod * x;
+      }
+      result.push(cum_prod);
+    }
+    result.into_vector()
+  }
+
+  fn cummin(&self) -> Vector<T> {
+    let mut cum_min = T::nan();
+    let mut result = Vec::with_capacity(self.len());
+    for &x in self.iter() {
+      if !x.is_nan() && (cum_min.is_nan() || x < cum_min) {
+        cum_min = x;
+      }
+      result.push(cum_min);
+    }
+    result.into_vector()
+  }
+
+  fn cummax(&self) -> Vector<T> {
+    let mut cum_max = T::nan();
+    let mut result = Vec::with_capacity(self.len());
+    for &x in self.iter() {
+      if !x.is_nan() && (cum_max.is_nan() || x > cum_max) {
+        cum_max = x;
+      }
+      result.push(cum_max);
+    }
+    result.into_vector()
+  }
+}
+
+/// Computes the quantile `q` of sorted, non-NaN values. Returns None if `values` is empty
+/// or `q` is outside [0, 1].
+pub(crate) fn quantile_sorted<T: Float>(values: &[T], q: T, method: QuantileMethod) -> Option<T> {
+  let n = values.len();
+  if n == 0 || q.is_nan() || q < T::zero() || q > T::one() {
+    return None;
+  }
+  let nt = T::from(n).unwrap();
+  let last = T::from(n - 1).unwrap();
+  let at = |i: T| values[i.max(T::zero()).min(last).to_usize().unwrap()];
+  // Interpolates at the zero-based position `h`, clamped to the data.
+  let interpolate = |h: T| {
+    let h = h.max(T::zero()).min(last);
+    let lo = h.floor();
+    if h == lo {
+      // Exactly on a value, which may be infinite.
+      return at(lo);
+    }
+    at(lo) + (at(h.ceil()) - at(lo)) * (h - lo)
+  };
+  let third = T::from(1.0 / 3.0).unwrap();
+  let value = match method {
+    QuantileMethod::Linear => interpolate(q * last),
+    QuantileMethod::Lower => at((q * last).floor()),
+    QuantileMethod::Higher => at((q * last).ceil()),
+    QuantileMethod::Nearest => {
+      let h = q * last;
+      let lo = h.floor();
+      let half = T::from(0.5).unwrap();
+      let even = lo.to_usize().unwrap() % 2 == 0;
+      if h - lo < half || (h - lo == half && even) {
+        at(lo)
+      } else {
+        at(lo + T::one())
+      }
+    }
+    QuantileMethod::Midpoint => {
+      let h = q * last;
+      (at(h.floor()) + at(h.ceil())) / T::from(2.0).unwrap()
+    }
+    QuantileMethod::InvertedCdf => at((nt * q).ceil() - T::one()),
+    QuantileMethod::AveragedInvertedCdf => {
+      let h = nt * q;
+      if h == h.floor() && h > T::zero() && h < nt {
+        (at(h - T::one()) + at(h)) / T::from(2.0).unwrap()
+      } else {
+        at(h.ceil() - T::one())
+      }
+    }
+    QuantileMethod::InterpolatedInvertedCdf => interpolate(nt * q - T::one()),
+    QuantileMethod::Hazen => interpolate(nt * q - T::from(0.5).unwrap()),
+    QuantileMethod::Weibull => interpolate((nt + T::one()) * q - T::one()),
+    QuantileMethod::MedianUnbiased => interpolate((nt + third) * q - T::from(2.0).unwrap() * third),
+    QuantileMethod::NormalUnbiased => {
+      interpolate((nt + T::from(0.25).unwrap()) * q - T::from(0.625).unwrap())
+    }
+  };
+  Some(value)
 }
@@ -26,6 +26,29 @@ pub struct Summary<T> {
   pub max: Option<T>,
 }
 
+/// Row labels of the summary table, in display order.
+const LABELS: [&str; 8] = ["Count", "Mean", "Std Dev", "Min", "25%", "Median", "75%", "Max"];
+
+impl<T> Summary<T>
+where
+  T: Float + fmt::Display,
+{
+  /// Returns the formatted values in the order of `LABELS`.
+  fn values(&self) -> [String; 8] {
+    let fmt = |v: Option<T>| format!("{:.4}", v.unwrap_or(T::nan()));
+    [
+      self.count.to_string(),
+      fmt(self.mean),
+      fmt(self.stddev),
+      fmt(self.min),
+      fmt(self.q25),
+      fmt(self.median),
+      fmt(self.q75),
+      fmt(self.max),
+    ]
+  }
+}
+
 impl<T> fmt::Display for Summary<T>
 where
   T: Float + fmt::Display,
@@ -34,35 +57,55 @@ where
     let mut table = Table::new();
     table.set_header(vec!["Statistic", "Value"]);
 
-    table.add_row(vec![Cell::new("Count"), Cell::new(self.count)]);
-    table.add_row(vec![
-      Cell::new("Mean"),
-      Cell::new(format!("{:.4}", self.mean.unwrap_or(T::nan()))),
-    ]);
-    table.add_row(vec![
-      Cell::new("Std Dev"),
-      Cell::new(format!("{:.4}", self.stddev.unwrap_or(T::nan()))),
-    ]);
-    table.add_row(vec![
-      Cell::new("Min"),
-      Cell::new(format!("{:.4}", self.min.unwrap_or(T::nan()))),
-    ]);
-    table.add_row(vec![
-      Cell::new("25%"),
-      Cell::new(format!("{:.4}", self.q25.unwrap_or(T::nan()))),
-    ]);
-    table.add_row(vec![
-      Cell::new("Median"),
-      Cell::new(format!("{:.4}", self.median.unwrap_or(T::nan()))),
-    ]);
-    table.add_row(vec![
-      Cell::new("75%"),
-      Cell::new(format!("{:.4}", self.q75.unwrap_or(T::nan()))),
-    ]);
-    table.add_row(vec![
-      Cell::new("Max"),
-      Cell::new(format!("{:.4}", self.max.unwrap_or(T::nan()))),
-    ]);
+    for (label, value) in LABELS.iter().zip(self.values()) {
+      table.add_row(vec![Cell::new(label), Cell::new(value)]);
+    }
+
+    write!(f, "{}", table)
+  }
+}
+
+/// Summary statistics of several columns, displayed as one table with a column per feature.
+pub struct ColumnSummary<T> {
+  /// Column headers, `"0"`, `"1"`, ... unless set with `with_names`.
+  pub names: Vec<String>,
+  /// Summary of each column.
+  pub columns: Vec<Summary<T>>,
+}
+
+impl<T> ColumnSummary<T> {
+  /// Creates a column summary with numeric column headers.
+  pub fn new(columns: Vec<Summary<T>>) -> Self {
+    let names = (0..columns.len()).map(|i| i.to_string()).collect();
+    ColumnSummary { names, columns }
+  }
+
+  /// Replaces the column headers. Missing names fall back to the column index,
+  /// and extra names are ignored.
+  pub fn with_names<S: Into<String>>(mut self, names: Vec<S>) -> Self {
+    for (slot, name) in self.names.iter_mut().zip(names) {
+      *slot = name.into();
+    }
+    self
+  }
+}
+
+impl<T> fmt::Display for ColumnSummary<T>
+where
+  T: Float + fmt::Display,
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut table = Table::new();
+    let mut header = vec!["Statistic".to_string()];
+    header.extend(self.names.iter().cloned());
+    table.set_header(header);
+
+    let values: Vec<[String; 8]> = self.columns.iter().map(|c| c.values()).collect();
+    for (i, label) in LABELS.iter().enumerate() {
+      let mut row = vec![Cell::new(label)];
+      row.extend(values.iter().map(|v| Cell::new(&v[i])));
+      table.add_row(row);
+    }
 
     write!(f, "{}", table)
   }
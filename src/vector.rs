@@ -1,5 +1,7 @@
 //! Defines the `Vector<T>` struct that wraps `Vec<T>` and provides conversion traits.
 
+use crate::error::MathError;
+use num_traits::Float;
 use std::ops::{Deref, DerefMut};
 
 /// A wrapper around `Vec<T>` to enable trait implementations.
@@ -18,6 +20,54 @@ impl<T> Vector<T> {
   }
 }
 
+impl<T: Float> Vector<T> {
+  /// Creates `num` evenly spaced values from `start` to `stop`, both included.
+  /// A single value is `start`.
+  pub fn linspace(start: T, stop: T, num: usize) -> Self {
+    if num == 1 {
+      return Vector(vec![start]);
+    }
+    let step = (stop - start) / T::from(num.saturating_sub(1)).unwrap();
+    Vector(
+      (0..num)
+        .map(|i| {
+          if i + 1 == num {
+            stop
+          } else {
+            start + step * T::from(i).unwrap()
+          }
+        })
+        .collect(),
+    )
+  }
+
+  /// Creates the values `start, start + step, ...` strictly before `stop`.
+  /// Returns an empty vector if `step` is zero, NaN, or points away from `stop`.
+  /// Returns an error if there are too many values to allocate, including infinitely many.
+  pub fn arange(start: T, stop: T, step: T) -> Result<Self, MathError> {
+    let count = ((stop - start) / step).ceil();
+    if step == T::zero() || count.is_nan() || count <= T::zero() {
+      return Ok(Vector(Vec::new()));
+    }
+    let too_many = || MathError::InvalidArgument("arange produces too many values".to_string());
+    let count = count.to_usize().ok_or_else(too_many)?;
+    let mut values = Vec::new();
+    values.try_reserve_exact(count).map_err(|_| too_many())?;
+    values.extend((0..count).map(|i| start + step * T::from(i).unwrap()));
+    Ok(Vector(values))
+  }
+
+  /// Creates `num` values spaced evenly on a log scale, from `base^start` to `base^stop`.
+  pub fn logspace(start: T, stop: T, num: usize, base: T) -> Self {
+    Vector(
+      Vector::linspace(start, stop, num)
+        .iter()
+        .map(|&e| base.powf(e))
+        .collect(),
+    )
+  }
+}
+
 impl<T> From<Vec<T>> for Vector<T> {
   fn from(vec: Vec<T>) -> Self {
     Vector(vec)
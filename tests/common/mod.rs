@@ -0,0 +1,27 @@
+//! Helpers shared by the integration tests.
+
+/// Asserts that `actual` is within relative tolerance `tol` of `expected`.
+#[allow(dead_code)]
+pub fn assert_close(actual: f64, expected: f64, tol: f64) {
+  assert!(
+    (actual - expected).abs() <= tol * expected.abs(),
+    "expected {}, found {}",
+    expected,
+    actual
+  );
+}
+
+/// Asserts that `actual` matches `expected` element-wise within absolute tolerance `tol`.
+#[allow(dead_code)]
+pub fn assert_all_close(actual: &[f64], expected: &[f64], tol: f64) {
+  assert_eq!(actual.len(), expected.len(), "lengths differ");
+  for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+    assert!(
+      (a - e).abs() <= tol,
+      "at index {}: expected {}, found {}",
+      i,
+      e,
+      a
+    );
+  }
+}
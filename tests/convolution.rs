@@ -0,0 +1,88 @@
+mod common;
+
+use common::assert_all_close;
+use math_ops::*;
+
+fn sample(n: usize, seed: f64) -> Vector<f64> {
+  (0..n)
+    .map(|i| (i as f64 * seed).sin() * 3.0 + (i % 5) as f64)
+    .collect::<Vec<f64>>()
+    .into_vector()
+}
+
+#[test]
+fn convolve_known_values() {
+  // The examples from numpy's `convolve` documentation.
+  let a = vec![1.0, 2.0, 3.0].into_vector();
+  let v = vec![0.0, 1.0, 0.5].into_vector();
+  for method in [ConvolveMethod::Direct, ConvolveMethod::Fft] {
+    let conv = |mode| a.convolve_with(&v, mode, method);
+    assert_all_close(&conv(ConvolveMode::Full), &[0.0, 1.0, 2.5, 4.0, 1.5], 1e-12);
+    assert_all_close(&conv(ConvolveMode::Same), &[1.0, 2.5, 4.0], 1e-12);
+    assert_all_close(&conv(ConvolveMode::Valid), &[2.5], 1e-12);
+  }
+  // numpy's `correlate` documentation.
+  assert_all_close(
+    &a.correlate(&v, ConvolveMode::Full),
+    &[0.5, 2.0, 3.5, 3.0, 0.0],
+    1e-12,
+  );
+  assert!(a
+    .convolve(&Vector::new(Vec::new()), ConvolveMode::Full)
+    .is_empty());
+}
+
+#[test]
+fn fft_convolution_matches_direct() {
+  for (n, m) in [
+    (1, 1),
+    (5, 3),
+    (3, 5),
+    (100, 65),
+    (65, 100),
+    (300, 80),
+    (257, 129),
+  ] {
+    let (a, b) = (sample(n, 0.31), sample(m, 1.7));
+    for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+      let direct = a.convolve_with(&b, mode, ConvolveMethod::Direct);
+      let fft = a.convolve_with(&b, mode, ConvolveMethod::Fft);
+      let auto = a.convolve(&b, mode);
+      let scale = direct.iter().fold(1.0_f64, |s, x| s.max(x.abs()));
+      assert_all_close(&fft, &direct, 1e-12 * scale);
+      assert_all_close(&auto, &direct, 1e-12 * scale);
+      let correlated = a.correlate_with(&b, mode, ConvolveMethod::Fft);
+      let expected = a.correlate_with(&b, mode, ConvolveMethod::Direct);
+      assert_all_close(&correlated, &expected, 1e-12 * scale);
+    }
+  }
+}
+
+#[test]
+fn a_single_nan_stays_local() {
+  // Long enough that `Auto` would pick the FFT for finite data.
+  let (n, m, at) = (200, 70, 100);
+  let mut a = sample(n, 0.31);
+  let b = sample(m, 1.7);
+  let clean = a.convolve_with(&b, ConvolveMode::Full, ConvolveMethod::Direct);
+  for bad in [f64::NAN, f64::INFINITY] {
+    a[at] = bad;
+    for method in [ConvolveMethod::Auto, ConvolveMethod::Direct] {
+      let full = a.convolve_with(&b, ConvolveMode::Full, method);
+      for (k, (&x, &c)) in full.iter().zip(clean.iter()).enumerate() {
+        if (at..at + m).contains(&k) {
+          assert!(!x.is_finite(), "output {} should be affected", k);
+        } else {
+          assert!(
+            (x - c).abs() <= 1e-12 * c.abs().max(1.0),
+            "output {} changed",
+            k
+          );
+        }
+      }
+    }
+    // The FFT spreads a non-finite value across the whole output, as documented.
+    let spread = a.convolve_with(&b, ConvolveMode::Full, ConvolveMethod::Fft);
+    assert!(spread.iter().all(|x| x.is_nan()));
+  }
+}
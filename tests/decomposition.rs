@@ -0,0 +1,273 @@
+mod common;
+
+use common::{assert_all_close, assert_close};
+use math_ops::*;
+
+fn matrix(rows: usize, cols: usize, data: &[f64]) -> Matrix<f64> {
+  Matrix::from_vec(rows, cols, data.to_vec()).unwrap()
+}
+
+fn assert_matrix_close(actual: &Matrix<f64>, expected: &Matrix<f64>, tol: f64) {
+  assert_eq!(actual.shape(), expected.shape());
+  assert_all_close(actual.as_slice(), expected.as_slice(), tol);
+}
+
+#[test]
+fn lu_solves_a_known_system() {
+  let a = matrix(3, 3, &[2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+  let lu = a.lu().unwrap();
+  assert!(!lu.is_singular());
+  assert_close(lu.determinant(), -16.0, 1e-12);
+  assert_close(a.determinant().unwrap(), -16.0, 1e-12);
+
+  let b = Vector::new(vec![5.0, -2.0, 9.0]);
+  assert_all_close(&lu.solve(&b).unwrap(), &[1.0, 1.0, 2.0], 1e-12);
+  assert_all_close(&a.solve(&b).unwrap(), &[1.0, 1.0, 2.0], 1e-12);
+
+  // P * A = L * U, with a unit lower-triangular L and an upper-triangular U.
+  let (l, u) = (lu.l(), lu.u());
+  let pa = Matrix::from_fn(3, 3, |i, j| a[(lu.permutation()[i], j)]);
+  assert_matrix_close(&l.matmul(&u).unwrap(), &pa, 1e-12);
+  for i in 0..3 {
+    assert_eq!(l[(i, i)], 1.0);
+    for j in i + 1..3 {
+      assert_eq!(l[(i, j)], 0.0);
+      assert_eq!(u[(j, i)], 0.0);
+    }
+  }
+  // Partial pivoting keeps every multiplier at most one in magnitude.
+  assert!(l.as_slice().iter().all(|x| x.abs() <= 1.0));
+}
+
+#[test]
+fn lu_inverse_of_a_known_matrix() {
+  let a = matrix(2, 2, &[4.0, 7.0, 2.0, 6.0]);
+  let expected = matrix(2, 2, &[0.6, -0.7, -0.2, 0.4]);
+  assert_matrix_close(&a.inverse().unwrap(), &expected, 1e-12);
+
+  let a = matrix(3, 3, &[2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+  let product = a.matmul(&a.inverse().unwrap()).unwrap();
+  assert_matrix_close(&product, &Matrix::identity(3), 1e-12);
+}
+
+#[test]
+fn lu_rejects_singular_matrices() {
+  let a = matrix(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+  let lu = a.lu().unwrap();
+  assert!(lu.is_singular());
+  assert!(lu.determinant().abs() < 1e-12);
+  let b = Vector::new(vec![1.0, 2.0, 3.0]);
+  assert_eq!(lu.solve(&b), Err(MathError::Singular));
+  assert_eq!(a.solve(&b), Err(MathError::Singular));
+  assert_eq!(a.inverse(), Err(MathError::Singular));
+
+  let zeros = Matrix::<f64>::zeros(2, 2);
+  assert_eq!(zeros.determinant(), Ok(0.0));
+  assert_eq!(zeros.inverse(), Err(MathError::Singular));
+}
+
+#[test]
+fn lu_rejects_non_square_and_mismatched_inputs() {
+  let a = matrix(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+  let shape = MathError::ShapeMismatch {
+    expected: (2, 2),
+    found: (2, 3),
+  };
+  assert_eq!(a.lu().err(), Some(shape.clone()));
+  assert_eq!(a.determinant(), Err(shape.clone()));
+  assert_eq!(a.inverse(), Err(shape.clone()));
+  assert_eq!(a.solve(&Vector::new(vec![1.0, 2.0])), Err(shape));
+
+  let square = matrix(2, 2, &[4.0, 7.0, 2.0, 6.0]);
+  assert_eq!(
+    square.solve(&Vector::new(vec![1.0, 2.0, 3.0])),
+    Err(MathError::LengthMismatch {
+      expected: 2,
+      found: 3
+    })
+  );
+}
+
+#[test]
+fn cholesky_of_a_known_matrix() {
+  let a = matrix(
+    3,
+    3,
+    &[4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0],
+  );
+  let chol = a.cholesky().unwrap();
+  let expected = matrix(3, 3, &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]);
+  assert_matrix_close(chol.l(), &expected, 1e-12);
+  assert_close(chol.determinant(), 36.0, 1e-12);
+
+  // b = A * [1, 2, 3]
+  let b = Vector::new(vec![-20.0, -43.0, 192.0]);
+  assert_all_close(&chol.solve(&b).unwrap(), &[1.0, 2.0, 3.0], 1e-12);
+  assert_eq!(
+    chol.solve(&Vector::new(vec![1.0, 2.0])),
+    Err(MathError::LengthMismatch {
+      expected: 3,
+      found: 2
+    })
+  );
+}
+
+#[test]
+fn cholesky_rejects_matrices_that_are_not_positive_definite() {
+  // Symmetric but indefinite, with eigenvalues 3 and -1.
+  let indefinite = matrix(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+  assert_eq!(
+    indefinite.cholesky().err(),
+    Some(MathError::NotPositiveDefinite)
+  );
+  // Positive semi-definite but singular.
+  let singular = matrix(2, 2, &[1.0, 1.0, 1.0, 1.0]);
+  assert_eq!(
+    singular.cholesky().err(),
+    Some(MathError::NotPositiveDefinite)
+  );
+  let negative = matrix(2, 2, &[-4.0, 0.0, 0.0, 1.0]);
+  assert_eq!(
+    negative.cholesky().err(),
+    Some(MathError::NotPositiveDefinite)
+  );
+  let nan = matrix(2, 2, &[f64::NAN, 0.0, 0.0, 1.0]);
+  assert_eq!(nan.cholesky().err(), Some(MathError::NotPositiveDefinite));
+
+  let non_square = matrix(2, 3, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+  assert_eq!(
+    non_square.cholesky().err(),
+    Some(MathError::ShapeMismatch {
+      expected: (2, 2),
+      found: (2, 3)
+    })
+  );
+}
+
+#[test]
+fn qr_factors_a_tall_matrix() {
+  let a = matrix(4, 2, &[0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0, 1.0]);
+  let qr = a.qr().unwrap();
+  assert!(qr.is_full_rank());
+  let (q, r) = (qr.q(), qr.r());
+  assert_eq!(q.shape(), (4, 2));
+  assert_eq!(r.shape(), (2, 2));
+  assert_eq!(r[(1, 0)], 0.0);
+  assert_matrix_close(&q.matmul(&r).unwrap(), &a, 1e-12);
+  assert_matrix_close(
+    &q.transpose().matmul(&q).unwrap(),
+    &Matrix::identity(2),
+    1e-12,
+  );
+}
+
+#[test]
+fn lstsq_fits_a_line() {
+  // The example from numpy's `linalg.lstsq` documentation: y = m x + c.
+  let a = matrix(4, 2, &[0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0, 1.0]);
+  let y = Vector::new(vec![-1.0, 0.2, 0.9, 2.1]);
+  assert_all_close(&a.lstsq(&y).unwrap(), &[1.0, -0.95], 1e-12);
+
+  // A square system is solved exactly.
+  let a = matrix(3, 3, &[2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+  let b = Vector::new(vec![5.0, -2.0, 9.0]);
+  assert_all_close(&a.lstsq(&b).unwrap(), &[1.0, 1.0, 2.0], 1e-12);
+}
+
+#[test]
+fn qr_rejects_wide_and_rank_deficient_inputs() {
+  let wide = matrix(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+  assert_eq!(
+    wide.qr().err(),
+    Some(MathError::ShapeMismatch {
+      expected: (3, 3),
+      found: (2, 3)
+    })
+  );
+  assert!(wide.lstsq(&Vector::new(vec![1.0, 2.0])).is_err());
+
+  // The second column is twice the first.
+  let deficient = matrix(3, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+  let qr = deficient.qr().unwrap();
+  assert!(!qr.is_full_rank());
+  let b = Vector::new(vec![1.0, 2.0, 3.0]);
+  assert_eq!(qr.solve(&b), Err(MathError::Singular));
+
+  let tall = matrix(3, 1, &[1.0, 2.0, 3.0]);
+  assert_eq!(
+    tall.lstsq(&Vector::new(vec![1.0, 2.0])),
+    Err(MathError::LengthMismatch {
+      expected: 3,
+      found: 2
+    })
+  );
+}
+
+/// Checks `A v = λ v` for every eigenpair and that the eigenvectors are orthonormal.
+fn assert_eigenpairs(a: &Matrix<f64>, eigen: &SymmetricEigen<f64>, tol: f64) {
+  let n = a.nrows();
+  for j in 0..n {
+    let v = eigen.vectors.col(j);
+    let av = a.matvec(&v).unwrap();
+    let lv: Vec<f64> = v.iter().map(|&x| eigen.values[j] * x).collect();
+    assert_all_close(&av, &lv, tol);
+  }
+  let vtv = eigen.vectors.transpose().matmul(&eigen.vectors).unwrap();
+  assert_matrix_close(&vtv, &Matrix::identity(n), tol);
+  assert!(eigen.values.windows(2).all(|w| w[0] >= w[1]));
+}
+
+#[test]
+fn symmetric_eigen_of_a_known_matrix() {
+  let a = matrix(2, 2, &[2.0, 1.0, 1.0, 2.0]);
+  let eigen = a.symmetric_eigen().unwrap();
+  assert_all_close(&eigen.values, &[3.0, 1.0], 1e-12);
+  let h = std::f64::consts::FRAC_1_SQRT_2;
+  assert_all_close(&eigen.vectors.col(0), &[h, h], 1e-12);
+  assert_eigenpairs(&a, &eigen, 1e-12);
+
+  let a = matrix(3, 3, &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+  let eigen = a.symmetric_eigen().unwrap();
+  let s = std::f64::consts::SQRT_2;
+  assert_all_close(&eigen.values, &[2.0 + s, 2.0, 2.0 - s], 1e-12);
+  assert_eigenpairs(&a, &eigen, 1e-12);
+}
+
+#[test]
+fn symmetric_eigen_satisfies_the_eigen_equation() {
+  let n = 6;
+  let a = Matrix::from_fn(n, n, |i, j| {
+    let (i, j) = (i.min(j) as f64, i.max(j) as f64);
+    (1.0 + i * 0.7 - j * 0.3).sin() * 4.0 + if i == j { i } else { 0.0 }
+  });
+  let eigen = a.symmetric_eigen().unwrap();
+  assert_eigenpairs(&a, &eigen, 1e-10);
+  let trace: f64 = a.diagonal().iter().sum();
+  assert_close(eigen.values.iter().sum(), trace, 1e-12);
+
+  // Repeated eigenvalues still give an orthonormal basis.
+  let a = matrix(3, 3, &[2.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0]);
+  let eigen = a.symmetric_eigen().unwrap();
+  assert_all_close(&eigen.values, &[4.0, 1.0, 1.0], 1e-12);
+  assert_eigenpairs(&a, &eigen, 1e-12);
+
+  let diagonal = Matrix::from_diagonal(&Vector::new(vec![1.0, -3.0, 5.0]));
+  let eigen = diagonal.symmetric_eigen().unwrap();
+  assert_all_close(&eigen.values, &[5.0, 1.0, -3.0], 0.0);
+  assert_eigenpairs(&diagonal, &eigen, 0.0);
+}
+
+#[test]
+fn symmetric_eigen_reads_only_the_upper_triangle() {
+  let upper = matrix(2, 2, &[2.0, 1.0, 99.0, 2.0]);
+  assert_all_close(&upper.symmetric_eigen().unwrap().values, &[3.0, 1.0], 1e-12);
+
+  let non_square = matrix(2, 3, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+  assert_eq!(
+    non_square.symmetric_eigen().err(),
+    Some(MathError::ShapeMismatch {
+      expected: (2, 2),
+      found: (2, 3)
+    })
+  );
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::assert_close;
+use math_ops::*;
+
+#[test]
+fn chi_squared_and_gamma_large_shape() {
+  let chi = ChiSquared::new(2e6).unwrap();
+  assert_close(chi.pdf(2e6), 1.994_711_235_781_220_2e-4, 1e-13);
+  assert_close(chi.cdf(2e6), 0.500_132_980_760_872_5, 1e-13);
+  assert_close(chi.sf(2e6), 1.0 - 0.500_132_980_760_872_5, 1e-13);
+  assert_close(chi.cdf(chi.ppf(0.3)), 0.3, 1e-12);
+  let gamma = Gamma::new(1e7, 0.5).unwrap();
+  assert_close(gamma.pdf(5.001e6), 2.065_408_170_590_752_5e-4, 1e-13);
+  assert_close(gamma.sf(5.001e6), 0.263_523_970_151_294_86, 1e-13);
+  assert_close(gamma.cdf(gamma.ppf(0.7)), 0.7, 1e-12);
+}
+
+#[test]
+fn beta_student_t_and_f_large_parameters() {
+  let beta = Beta::new(1e7, 3e7).unwrap();
+  assert_close(beta.pdf(0.25), 5_826.924_910_553_572, 1e-13);
+  assert_close(beta.cdf(beta.ppf(0.3)), 0.3, 1e-12);
+  let t = StudentT::new(1e7).unwrap();
+  assert_close(t.pdf(1.3), 0.171_368_585_519_092_37, 1e-13);
+  let f = FisherF::new(80000.0, 1.4).unwrap();
+  assert_close(f.cdf(0.8), 0.277_316_947_737_556_8, 1e-13);
+  assert_close(f.sf(0.8), 1.0 - 0.277_316_947_737_556_8, 1e-13);
+}
+
+#[test]
+fn poisson_large_mean() {
+  let poisson = Poisson::new(1e5).unwrap();
+  assert_close(poisson.pmf(100_000), 0.001_261_565_209_705_300_5, 1e-13);
+  assert_close(poisson.cdf(100_000), 0.500_841_043_099_340_1, 1e-13);
+  assert_close(poisson.sf(100_000), 0.499_158_956_900_659_87, 1e-13);
+  assert_eq!(poisson.ppf(0.5), Some(100_000));
+}
+
+#[test]
+fn binomial_many_trials() {
+  let binomial = Binomial::new(10_000_000, 0.3).unwrap();
+  assert_close(binomial.pmf(3_000_500), 2.593_759_389_824_772e-4, 1e-13);
+  assert_close(binomial.cdf(3_000_500), 0.635_110_049_093_875_5, 1e-13);
+  assert_close(binomial.sf(3_000_500), 1.0 - 0.635_110_049_093_875_5, 1e-13);
+  let k = binomial.ppf(0.3).unwrap();
+  assert!(binomial.cdf(k - 1) < 0.3 && binomial.cdf(k) >= 0.3);
+  let rare = Binomial::new(100_000_000, 1e-6).unwrap();
+  assert_close(rare.pmf(105), 0.034_400_671_499_196_93, 1e-13);
+  assert_close(rare.cdf(105), 0.712_807_968_420_248_5, 1e-13);
+}
+
+#[test]
+fn cauchy_far_tails() {
+  let cauchy = StudentT::new(1.0).unwrap();
+  assert_close(cauchy.cdf(-1e200), 3.183_098_861_837_906_7e-201, 1e-13);
+  assert_close(cauchy.sf(1e160), 3.183_098_861_837_906_7e-161, 1e-13);
+  assert_close(cauchy.cdf(-1e10), 3.183_098_861_837_906_5e-11, 1e-13);
+  assert_eq!(cauchy.cdf(1e200), 1.0);
+  assert_close(cauchy.ppf(1e-200), -3.183_098_861_837_907e199, 1e-13);
+  assert_close(cauchy.ppf(1e-5), -31_830.988_607_907_09, 1e-13);
+}
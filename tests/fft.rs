@@ -0,0 +1,88 @@
+mod common;
+
+use common::assert_all_close;
+use math_ops::*;
+use std::f64::consts::PI;
+
+/// A deterministic complex signal of length `n`.
+fn signal(n: usize) -> Vec<Complex<f64>> {
+  (0..n)
+    .map(|j| {
+      let t = j as f64;
+      Complex::new((t * 0.37).sin() + 0.25 * t.sqrt(), (t * 1.91).cos() - 0.5)
+    })
+    .collect()
+}
+
+/// The transform by its definition, in O(n^2).
+fn naive_dft(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
+  let n = x.len();
+  (0..n)
+    .map(|k| {
+      x.iter()
+        .enumerate()
+        .fold(Complex::new(0.0, 0.0), |acc, (j, &v)| {
+          let angle = -2.0 * PI * ((j * k) % n) as f64 / n as f64;
+          acc + v * Complex::new(angle.cos(), angle.sin())
+        })
+    })
+    .collect()
+}
+
+fn assert_complex_close(actual: &[Complex<f64>], expected: &[Complex<f64>], tol: f64) {
+  let re = |z: &[Complex<f64>]| z.iter().map(|c| c.re).collect::<Vec<f64>>();
+  let im = |z: &[Complex<f64>]| z.iter().map(|c| c.im).collect::<Vec<f64>>();
+  assert_all_close(&re(actual), &re(expected), tol);
+  assert_all_close(&im(actual), &im(expected), tol);
+}
+
+#[test]
+fn fft_of_known_inputs() {
+  let x = [1.0, 2.0, 3.0, 4.0].map(|v| Complex::new(v, 0.0));
+  let expected =
+    [(10.0, 0.0), (-2.0, 2.0), (-2.0, 0.0), (-2.0, -2.0)].map(|(r, i)| Complex::new(r, i));
+  assert_complex_close(&fft(&x), &expected, 1e-12);
+
+  let single = [Complex::new(3.5, -1.0)];
+  assert_eq!(fft(&single), single);
+  assert_eq!(ifft(&single), single);
+  assert!(fft::<f64>(&[]).is_empty());
+
+  // An impulse has a flat spectrum at any length.
+  let mut impulse = vec![Complex::new(0.0, 0.0); 7];
+  impulse[0] = Complex::new(1.0, 0.0);
+  assert_complex_close(&fft(&impulse), &[Complex::new(1.0, 0.0); 7], 1e-12);
+}
+
+#[test]
+fn fft_matches_the_definition() {
+  for n in [1, 2, 3, 7, 8, 12, 17, 100, 128] {
+    let x = signal(n);
+    let tol = 1e-12 * n as f64;
+    assert_complex_close(&fft(&x), &naive_dft(&x), tol);
+  }
+}
+
+#[test]
+fn ifft_inverts_fft() {
+  for n in [1, 2, 7, 12, 1024, 1000] {
+    let x = signal(n);
+    assert_complex_close(&ifft(&fft(&x)), &x, 1e-12);
+    assert_complex_close(&fft(&ifft(&x)), &x, 1e-12);
+  }
+}
+
+#[test]
+fn rfft_keeps_the_non_negative_frequencies() {
+  for n in [1, 2, 7, 12] {
+    let x: Vec<f64> = signal(n).iter().map(|z| z.re).collect();
+    let full = x.clone().into_vector().fft();
+    let half = x.into_vector().rfft();
+    assert_eq!(half.len(), n / 2 + 1);
+    assert_complex_close(&half, &full[..n / 2 + 1], 0.0);
+    // The spectrum of real data is conjugate symmetric.
+    for k in 1..n {
+      assert!((full[k] - full[n - k].conj()).norm() < 1e-12);
+    }
+  }
+}
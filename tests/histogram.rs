@@ -0,0 +1,47 @@
+use math_ops::*;
+
+#[test]
+fn width_and_rule_bins() {
+  let data = vec![0.0, 0.4, 1.1, 2.0].into_vector();
+  let histogram = Histogram::new(&data, Bins::Width(0.5)).unwrap();
+  assert_eq!(histogram.edges().to_vec(), vec![0.0, 0.5, 1.0, 1.5, 2.0]);
+  assert_eq!(histogram.counts(), &[2, 0, 1, 1]);
+
+  // A rule whose width is zero falls back to a single bin.
+  let constant = vec![3.0; 8].into_vector();
+  let histogram = Histogram::new(&constant, Bins::Rule(BinRule::FreedmanDiaconis)).unwrap();
+  assert_eq!(histogram.len(), 1);
+  assert_eq!(histogram.total(), 8);
+}
+
+#[test]
+fn too_many_bins_is_an_error() {
+  let data = vec![0.0, 1.0].into_vector();
+  // The count does not fit in usize.
+  assert!(matches!(
+    Histogram::new(&data, Bins::Width(1e-300)),
+    Err(MathError::InvalidArgument(_))
+  ));
+  // The count fits, but is far more than the data calls for.
+  assert!(Histogram::new(&data, Bins::Width(1e-9)).is_err());
+  let width = 2.0 / MAX_BINS as f64;
+  assert_eq!(
+    Histogram::new(&data, Bins::Width(width)).unwrap().len(),
+    MAX_BINS / 2
+  );
+
+  // A tight cluster and one far outlier give a tiny Freedman-Diaconis width.
+  let mut values: Vec<f64> = (0..100).map(|i| i as f64 * 1e-6).collect();
+  values.push(1e9);
+  let data = values.into_vector();
+  assert!(matches!(
+    Histogram::new(&data, Bins::Rule(BinRule::FreedmanDiaconis)),
+    Err(MathError::InvalidArgument(_))
+  ));
+  assert_eq!(
+    Histogram::new(&data, Bins::Rule(BinRule::Sturges))
+      .unwrap()
+      .len(),
+    8
+  );
+}
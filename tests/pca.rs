@@ -0,0 +1,113 @@
+mod common;
+
+use common::{assert_all_close, assert_close};
+use math_ops::*;
+
+/// Points scattered along the direction (1, 2, 0), with smaller spreads along (2, -1, 1)
+/// and the z axis.
+fn sample() -> Matrix<f64> {
+  Matrix::from_fn(20, 3, |i, j| {
+    let t = i as f64 - 9.5;
+    let s = (i as f64 * 1.3).sin();
+    let r = (i as f64 * 2.1).cos() * 0.5;
+    [t + 2.0 * s, 2.0 * t - s, s + r][j] + [1.0, -2.0, 3.0][j]
+  })
+}
+
+#[test]
+fn pca_components_are_orthonormal_eigenvectors_of_the_covariance() {
+  let data = sample();
+  let options = PcaOptions {
+    standardize: false,
+    ..PcaOptions::default()
+  };
+  let pca = Pca::fit(&data, options).unwrap();
+  let components = &pca.components;
+  assert_eq!(components.shape(), (3, 3));
+
+  let product = components.matmul(&components.transpose()).unwrap();
+  assert_all_close(product.as_slice(), Matrix::identity(3).as_slice(), 1e-12);
+
+  let cov = data.cols().as_slice().covariance().unwrap();
+  for k in 0..3 {
+    let v = components.row(k);
+    let cv = cov.matvec(&v).unwrap();
+    let lv: Vec<f64> = v.iter().map(|&x| pca.explained_variance[k] * x).collect();
+    assert_all_close(&cv, &lv, 1e-9);
+  }
+  assert!(pca.explained_variance.windows(2).all(|w| w[0] >= w[1]));
+  let total: f64 = cov.diagonal().iter().sum();
+  assert_close(pca.explained_variance.iter().sum(), total, 1e-12);
+  assert_close(pca.explained_variance_ratio.iter().sum(), 1.0, 1e-12);
+
+  // The leading axis follows the main direction of the data.
+  let main = [1.0, 2.0, 0.0].map(|x: f64| x / 5.0_f64.sqrt());
+  assert!(components
+    .row(0)
+    .iter()
+    .zip(main)
+    .all(|(a, b)| (a.abs() - b).abs() < 0.05));
+}
+
+#[test]
+fn pca_of_axis_aligned_data() {
+  let data = Matrix::from_vec(4, 2, vec![2.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, -1.0]).unwrap();
+  let options = PcaOptions {
+    standardize: false,
+    ..PcaOptions::default()
+  };
+  let pca = Pca::fit(&data, options).unwrap();
+  assert_all_close(&pca.explained_variance, &[2.0, 0.5], 1e-12);
+  assert_all_close(&pca.explained_variance_ratio, &[0.8, 0.2], 1e-12);
+  assert_all_close(pca.components.as_slice(), &[1.0, 0.0, 0.0, 1.0], 1e-12);
+}
+
+#[test]
+fn pca_projections_are_uncorrelated() {
+  let data = sample();
+  for standardize in [false, true] {
+    for whiten in [false, true] {
+      let options = PcaOptions {
+        n_components: None,
+        whiten,
+        standardize,
+      };
+      let pca = Pca::fit(&data, options).unwrap();
+      let projected = pca.transform(&data).unwrap();
+      let cov = projected.cols().as_slice().covariance().unwrap();
+      for i in 0..3 {
+        for j in 0..3 {
+          let expected = match (i == j, whiten) {
+            (false, _) => 0.0,
+            (true, false) => pca.explained_variance[i],
+            (true, true) => 1.0,
+          };
+          assert!((cov[(i, j)] - expected).abs() < 1e-9);
+        }
+      }
+      let restored = pca.inverse_transform(&projected).unwrap();
+      assert_all_close(restored.as_slice(), data.as_slice(), 1e-9);
+    }
+  }
+}
+
+#[test]
+fn pca_rejects_invalid_inputs() {
+  let one_row = Matrix::from_vec(1, 2, vec![1.0, 2.0]).unwrap();
+  assert!(Pca::fit(&one_row, PcaOptions::default()).is_err());
+  let options = PcaOptions {
+    n_components: Some(4),
+    ..PcaOptions::default()
+  };
+  assert!(Pca::fit(&sample(), options).is_err());
+
+  let options = PcaOptions {
+    n_components: Some(1),
+    ..PcaOptions::default()
+  };
+  let pca = Pca::fit(&sample(), options).unwrap();
+  assert_eq!(pca.components.shape(), (1, 3));
+  assert_eq!(pca.transform(&sample()).unwrap().shape(), (20, 1));
+  let wrong = Matrix::from_vec(1, 2, vec![1.0, 2.0]).unwrap();
+  assert!(pca.transform(&wrong).is_err());
+}
@@ -0,0 +1,96 @@
+use math_ops::*;
+
+/// Pseudo-random values drawn from a few levels, so windows hold duplicates, with every
+/// seventh value NaN.
+fn sample(n: usize) -> Vector<f64> {
+  let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+  (0..n)
+    .map(|i| {
+      seed ^= seed << 13;
+      seed ^= seed >> 7;
+      seed ^= seed << 17;
+      if i % 7 == 3 {
+        f64::NAN
+      } else {
+        (seed % 9) as f64 - 4.0
+      }
+    })
+    .collect::<Vec<f64>>()
+    .into_vector()
+}
+
+/// The quantile of each window, found by sorting its values.
+fn naive(data: &[f64], window: usize, min_periods: usize, center: bool, q: f64) -> Vec<f64> {
+  let offset = if center { (window - 1) / 2 } else { 0 };
+  (0..data.len())
+    .map(|i| {
+      let end = (i + 1 + offset).min(data.len());
+      let start = (i + 1 + offset).saturating_sub(window);
+      let values: Vec<f64> = data[start..end]
+        .iter()
+        .cloned()
+        .filter(|x| !x.is_nan())
+        .collect();
+      if values.len() < min_periods.max(1) {
+        f64::NAN
+      } else {
+        values.into_vector().quantile(q).unwrap()
+      }
+    })
+    .collect()
+}
+
+fn assert_same(actual: &[f64], expected: &[f64]) {
+  assert_eq!(actual.len(), expected.len());
+  for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+    assert!(
+      (a.is_nan() && e.is_nan()) || (a - e).abs() <= 1e-12,
+      "at index {}: expected {}, found {}",
+      i,
+      e,
+      a
+    );
+  }
+}
+
+#[test]
+fn rolling_quantile_matches_sorting_each_window() {
+  let data = sample(60);
+  for window in [1, 2, 3, 4, 10, 59, 60, 80] {
+    for min_periods in [1, 2, window] {
+      for center in [false, true] {
+        for q in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+          let rolling = data.rolling(window).min_periods(min_periods).center(center);
+          assert_same(
+            &rolling.quantile(q),
+            &naive(&data, window, min_periods, center, q),
+          );
+        }
+        let rolling = data.rolling(window).min_periods(min_periods).center(center);
+        assert_same(
+          &rolling.median(),
+          &naive(&data, window, min_periods, center, 0.5),
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn rolling_quantile_edge_cases() {
+  let data = vec![2.0, f64::NAN, f64::NAN, 2.0, 2.0, 5.0].into_vector();
+  assert_same(
+    &data.rolling(2).median(),
+    &[f64::NAN, f64::NAN, f64::NAN, f64::NAN, 2.0, 3.5],
+  );
+  assert_same(
+    &data.rolling(3).min_periods(1).median(),
+    &[2.0, 2.0, 2.0, 2.0, 2.0, 2.0],
+  );
+  assert_same(&data.rolling(1).quantile(0.3), &data);
+  assert_same(
+    &data.expanding().quantile(0.75),
+    &[2.0, 2.0, 2.0, 2.0, 2.0, 2.75],
+  );
+  assert!(data.rolling(2).quantile(1.5).iter().all(|x| x.is_nan()));
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use common::assert_all_close;
+use math_ops::*;
+
+#[test]
+fn gaussian_smooth_matches_scipy() {
+  // Reference values from scipy.ndimage.gaussian_filter1d with the default mode.
+  let x = vec![1.0, 2.0, 3.0, 4.0, 5.0].into_vector();
+  let narrow = x.gaussian_smooth(1.0).unwrap();
+  assert_all_close(
+    &narrow,
+    &[1.42704095, 2.06782203, 3.0, 3.93217797, 4.57295905],
+    1e-8,
+  );
+  // The kernel radius of 16 is wider than the input, so it wraps the mirrored copies.
+  let wide = x.gaussian_smooth(4.0).unwrap();
+  assert_all_close(
+    &wide,
+    &[
+      2.919_483_431_978_690_6,
+      2.950_235_024_493_935_5,
+      3.0,
+      3.049_764_975_506_064_5,
+      3.080_516_568_021_309_4,
+    ],
+    1e-14,
+  );
+  let y = vec![0.5, -1.25, 3.0, 2.0, 7.5, -0.75].into_vector();
+  assert_all_close(
+    &y.gaussian_smooth(2.5).unwrap(),
+    &[
+      1.146_077_468_596_979_7,
+      1.360_254_263_962_711_8,
+      1.702_407_662_768_265_6,
+      2.049_491_244_509_840_5,
+      2.306_433_473_436_481,
+      2.435_335_886_725_721_5,
+    ],
+    1e-14,
+  );
+}
+
+#[test]
+fn savgol_matches_scipy() {
+  // The example from scipy.signal.savgol_filter's documentation, with mode="interp".
+  let x = vec![2.0, 2.0, 5.0, 2.0, 1.0, 0.0, 1.0, 4.0, 9.0].into_vector();
+  assert_all_close(
+    &x.savgol(5, 2).unwrap(),
+    &[
+      1.657_142_857_142_857_3,
+      3.171_428_571_428_571_3,
+      3.542_857_142_857_142_7,
+      2.857_142_857_142_857,
+      0.657_142_857_142_857_1,
+      0.171_428_571_428_571_43,
+      1.0,
+      4.0,
+      9.0,
+    ],
+    1e-13,
+  );
+  let y = vec![
+    0.5, -1.25, 3.0, 2.0, 7.5, -0.75, 1.0, 4.25, -2.0, 3.5, 0.0, 6.0,
+  ]
+  .into_vector();
+  assert_all_close(
+    &y.savgol(7, 3).unwrap(),
+    &[
+      -0.190_476_190_476_190_47,
+      0.464_285_714_285_714_3,
+      1.857_142_857_142_857_2,
+      3.238_095_238_095_238,
+      3.142_857_142_857_143,
+      2.976_190_476_190_476_3,
+      1.595_238_095_238_095_3,
+      0.809_523_809_523_809_5,
+      1.190_476_190_476_190_5,
+      0.529_761_904_761_904_8,
+      1.547_619_047_619_047_7,
+      5.660_714_285_714_286,
+    ],
+    1e-13,
+  );
+  assert!(y.savgol(4, 2).is_err());
+  assert!(y.savgol(5, 5).is_err());
+  assert!(y.savgol(13, 2).is_err());
+}
+
+#[test]
+fn lowess_matches_statsmodels() {
+  // A line with one outlier at index 7. Reference values follow statsmodels'
+  // `lowess(y, x, frac=0.5, it=...)` with `delta=0`.
+  let y = vec![
+    1.0, 1.8, 3.1, 3.9, 5.2, 5.8, 7.1, 30.0, 9.2, 9.8, 11.1, 12.2, 12.9, 14.1, 15.0, 16.2, 16.8,
+    18.1, 19.0, 20.2,
+  ]
+  .into_vector();
+  assert_all_close(
+    &y.lowess(0.5, 0).unwrap(),
+    &[
+      -0.163_898_809_167_566_9,
+      1.386_438_119_549_994,
+      3.062_730_483_284_926_5,
+      4.884_218_561_801_1,
+      6.831_102_327_138_51,
+      9.135_363_925_793_023,
+      10.729_312_277_744_143,
+      11.821_453_907_656_547,
+      12.741_221_888_130_536,
+      13.158_910_882_725_857,
+      12.873_219_363_794_888,
+      12.482_726_592_071_693,
+      13.047_482_629_713_837,
+      14.046_743_372_623_872,
+      15.035_075_263_500_751,
+      16.028_860_568_488_525,
+      17.044_797_801_250_805,
+      18.060_036_941_285_937,
+      19.075_618_940_033_97,
+      20.093_198_159_211_067,
+    ],
+    1e-12,
+  );
+  // The robustness passes discount the outlier.
+  assert_all_close(
+    &y.lowess(0.5, 3).unwrap(),
+    &[
+      0.940_849_325_010_473_8,
+      1.958_621_000_105_774,
+      2.975_471_215_990_637_6,
+      3.992_808_924_935_535,
+      5.013_505_893_129_979,
+      6.040_570_717_887_903,
+      7.046_410_779_826_205,
+      8.053_223_568_495_927,
+      9.064_476_806_593_431,
+      10.076_550_046_011_52,
+      11.072_487_286_899_486,
+      12.060_851_929_177_366,
+      13.059_387_094_624_775,
+      14.054_719_791_107_015,
+      15.043_389_157_092_603,
+      16.038_296_589_985_8,
+      17.053_589_437_944_648,
+      18.068_411_708_485_13,
+      19.083_275_021_888_62,
+      20.099_528_422_525_722,
+    ],
+    1e-12,
+  );
+  assert!(y.lowess(0.0, 3).is_err());
+  assert!(y.lowess(1.5, 3).is_err());
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use common::assert_close;
+use math_ops::*;
+
+#[test]
+fn gamma_inc_large_a() {
+  assert_close(gamma_inc(5000.0, 5000.0), 0.501_880_634_033_817_4, 1e-14);
+  assert_close(gamma_inc(1e4, 1e4), 0.501_329_808_339_955_2, 1e-14);
+  assert_close(gamma_inc(1e5, 1e5), 0.500_420_522_110_365_3, 1e-14);
+  assert_close(gamma_inc(1e6, 1e6), 0.500_132_980_760_872_6, 1e-14);
+  assert_close(gamma_inc(1e4, 9900.0), 0.158_651_192_193_564_66, 1e-13);
+  assert_close(
+    gamma_inc_upper(1e4, 10100.0),
+    0.158_651_249_552_820_37,
+    1e-13,
+  );
+  assert_close(gamma_inc(1e5, 99000.0), 7.574_199_211_747_68e-4, 1e-13);
+  assert_close(
+    gamma_inc_upper(1e5, 101000.0),
+    8.084_215_129_255_907e-4,
+    1e-13,
+  );
+  assert_close(gamma_inc(1e6, 990000.0), 5.446_644_693_010_809e-24, 1e-12);
+  assert_close(
+    gamma_inc_upper(1e6, 1010000.0),
+    1.060_699_747_758_69e-23,
+    1e-12,
+  );
+}
+
+#[test]
+fn beta_inc_large_parameters() {
+  for a in [5e5, 5e6, 5e7] {
+    assert_close(beta_inc(a, a, 0.5), 0.5, 1e-12);
+  }
+  assert_close(beta_inc(5e5, 5e5, 0.5005), 0.841_344_746_068_546_6, 1e-12);
+  assert_close(beta_inc(5e5, 5e5, 0.4995), 0.158_655_253_931_453_44, 1e-12);
+  assert_close(beta_inc(50.0, 5e5, 1.2e-4), 0.915_720_078_859_878, 1e-13);
+  assert_close(
+    beta_inc(5e5, 50.0, 1.0 - 1.2e-4),
+    0.084_279_921_140_034_94,
+    1e-13,
+  );
+  assert_close(beta_inc(1e3, 1e6, 1.1e-3), 0.999_056_137_210_256_2, 1e-13);
+  assert_close(
+    beta_inc(1e6, 1e3, 1.0 - 1.1e-3),
+    9.438_627_897_447_409e-4,
+    1e-12,
+  );
+  assert_close(
+    beta_inc(1e7, 1e4, 1.0 - 1.05e-3),
+    2.497_073_691_311_803e-7,
+    1e-12,
+  );
+}
+
+#[test]
+fn gamma_and_digamma_precision() {
+  assert_close(gamma(170.0), 4.269_068_009_004_705e304, 1e-15);
+  assert_close(gamma(30.5), 4.822_696_933_490_909e31, 1e-15);
+  assert_close(digamma(-0.5), 0.036_489_973_978_576_52, 1e-15);
+}
+
+#[test]
+fn element_wise_beta_and_binomial_coefficient() {
+  let x = vec![2.5, 4.0].into_vector();
+  let b = x.beta(3.0);
+  assert_close(b[0], 0.050_793_650_793_650_794, 1e-14);
+  assert_close(b[1], 1.0 / 60.0, 1e-14);
+  let n = vec![10.0, 10.5].into_vector();
+  let c = n.binomial_coefficient(3.0);
+  assert_close(c[0], 120.0, 1e-14);
+  assert_close(c[1], 141.3125, 1e-14);
+}
+
+#[test]
+fn erf_and_erfc_ordinary_arguments() {
+  assert_close(erf(1e-10), 1.128_379_167_095_512_6e-10, 1e-15);
+  assert_close(erf(0.1), 0.112_462_916_018_284_9, 1e-15);
+  assert_close(erf(0.5), 0.520_499_877_813_046_5, 1e-15);
+  assert_close(erf(1.0), 0.842_700_792_949_714_9, 1e-15);
+  assert_close(erf(2.0), 0.995_322_265_018_952_7, 1e-15);
+  assert_close(erf(-0.3), -0.328_626_759_459_127_4, 1e-15);
+  assert_close(erf(3.5), 0.999_999_256_901_627_6, 1e-15);
+  assert_eq!(erf(0.0), 0.0);
+
+  assert_close(erfc(1e-10), 0.999_999_999_887_162, 1e-15);
+  assert_close(erfc(0.5), 0.479_500_122_186_953_5, 1e-15);
+  assert_close(erfc(1.0), 0.157_299_207_050_285_13, 1e-15);
+  assert_close(erfc(3.0), 2.209_049_699_858_544e-5, 1e-14);
+  assert_close(erfc(10.0), 2.088_487_583_762_545e-45, 1e-14);
+  assert_close(erfc(26.0), 5.663_192_408_856_143e-296, 1e-13);
+  assert_close(erfc(-1.0), 1.842_700_792_949_714_8, 1e-15);
+}
+
+#[test]
+fn erfinv_ordinary_arguments() {
+  assert_close(erfinv(1e-8), 8.862_269_254_527_58e-9, 1e-15);
+  assert_close(erfinv(0.1), 0.088_855_990_494_257_69, 1e-15);
+  assert_close(erfinv(0.5), 0.476_936_276_204_469_9, 1e-15);
+  assert_close(erfinv(-0.9), -1.163_087_153_676_674_3, 1e-15);
+  assert_close(erfinv(0.999_999), 3.458_910_737_275_499, 1e-13);
+  assert_close(erfinv(-0.999_999_999), -4.320_005_388_105_362, 1e-12);
+  assert_eq!(erfinv(0.0), 0.0);
+  assert_eq!(erfinv(1.0), f64::INFINITY);
+  assert!(erfinv(1.5_f64).is_nan());
+}
+
+#[test]
+fn lgamma_near_one_and_two() {
+  assert_eq!(lgamma(1.0), 0.0);
+  assert_eq!(lgamma(2.0), 0.0);
+  assert_close(lgamma(0.999), 5.780_385_328_913_802e-4, 1e-13);
+  assert_close(lgamma(1.001), -5.763_935_982_833_062e-4, 1e-13);
+  assert_close(lgamma(1.000_000_01), -5.772_156_531_688_512e-9, 1e-12);
+  assert_close(lgamma(0.999_999_99), 5.772_156_760_265_731e-9, 1e-12);
+  assert_close(lgamma(1.999), -4.224_618_006_921_073e-4, 1e-13);
+  assert_close(lgamma(2.001), 4.231_067_348_001_17e-4, 1e-13);
+  assert_close(lgamma(2.000_000_01), 4.227_843_357_536_779e-9, 1e-12);
+  assert_close(lgamma(1.5), -0.120_782_237_635_245_22, 1e-14);
+  assert_close(lgamma(0.5), 0.572_364_942_924_700_1, 1e-14);
+  assert_close(lgamma(3.7), 1.428_072_326_665_388, 1e-14);
+}
+
+#[test]
+fn digamma_at_negative_non_integers() {
+  assert_close(digamma(-0.1), 9.245_073_050_052_948, 1e-14);
+  assert_close(digamma(-1.5), 0.703_156_640_645_243_2, 1e-14);
+  assert_close(digamma(-2.7), -1.115_347_129_140_689_6, 1e-14);
+  assert_close(digamma(-10.3), 4.662_403_493_582_087, 1e-13);
+  assert_close(digamma(-100.25), 7.754_238_959_208_646, 1e-13);
+  assert!(digamma(-2.0_f64).is_nan());
+}
+
+#[test]
+fn single_precision() {
+  // References are evaluated at the f32 arguments.
+  let check = |actual: f32, expected: f64| assert_close(f64::from(actual), expected, 1e-6);
+  check(erf(0.5_f32), 0.520_499_877_813_046_5);
+  check(erf(2.0_f32), 0.995_322_265_018_952_7);
+  check(erfc(3.0_f32), 2.209_049_699_858_544e-5);
+  check(erfinv(0.5_f32), 0.476_936_276_204_469_9);
+  check(gamma(4.5_f32), 11.631_728_396_567_448);
+  check(gamma(0.1_f32), 9.513_507_550_899_174);
+  check(lgamma(30.0_f32), 71.257_038_967_168_01);
+  check(lgamma(1.5_f32), -0.120_782_237_635_245_22);
+  check(digamma(0.5_f32), -1.963_510_026_021_423_5);
+  check(digamma(-1.5_f32), 0.703_156_640_645_243_2);
+  check(gamma_inc(3.0_f32, 2.5), 0.456_186_884_116_670_47);
+  check(beta_inc(2.0_f32, 3.5, 0.4), 0.598_449_097_136_471);
+  let v = vec![0.5_f32, 2.0].into_vector();
+  check(v.erf()[1], 0.995_322_265_018_952_7);
+}
@@ -0,0 +1,29 @@
+use math_ops::*;
+
+#[test]
+fn quantiles_at_infinite_values() {
+  let inf = f64::INFINITY;
+  let upper = vec![1.0, 2.0, inf].into_vector();
+  assert_eq!(upper.quantile(1.0), Some(inf));
+  assert_eq!(upper.quantile(0.5), Some(2.0));
+  assert_eq!(upper.quantile(0.75), Some(inf));
+  assert_eq!(
+    upper.quantile_with(1.0, QuantileMethod::InterpolatedInvertedCdf),
+    Some(inf)
+  );
+  let lower = vec![-inf, 0.0, 1.0].into_vector();
+  assert_eq!(lower.quantile(0.0), Some(-inf));
+  assert_eq!(lower.quantile(0.5), Some(0.0));
+  assert_eq!(lower.quantile_with(0.0, QuantileMethod::Hazen), Some(-inf));
+}
+
+#[test]
+fn median_and_iqr_with_infinite_values() {
+  let inf = f64::INFINITY;
+  assert_eq!(vec![1.0, inf, 2.0].into_vector().median(), Some(2.0));
+  assert_eq!(vec![-inf, 1.0, 2.0, inf].into_vector().median(), Some(1.5));
+  assert_eq!(vec![1.0, inf, 2.0, inf].into_vector().median(), Some(inf));
+  assert_eq!(vec![-inf, 1.0, 2.0, 3.0, inf].into_vector().iqr(), Some(2.0));
+  assert_eq!(vec![1.0, 2.0, 3.0, inf, inf].into_vector().iqr(), Some(inf));
+  assert_eq!(vec![-inf, -inf, 1.0, 2.0, 3.0].into_vector().iqr(), Some(inf));
+}
@@ -0,0 +1,29 @@
+use math_ops::*;
+
+#[test]
+fn arange_values() {
+  let v = Vector::arange(0.0, 1.0, 0.25).unwrap();
+  assert_eq!(v.to_vec(), vec![0.0, 0.25, 0.5, 0.75]);
+  let v = Vector::arange(3.0, 0.0, -1.5).unwrap();
+  assert_eq!(v.to_vec(), vec![3.0, 1.5]);
+  assert_eq!(Vector::arange(0.0, 2.5, 1.0).unwrap().len(), 3);
+}
+
+#[test]
+fn arange_empty_ranges() {
+  assert!(Vector::arange(0.0, 1.0, -1.0).unwrap().is_empty());
+  assert!(Vector::arange(1.0, 1.0, 1.0).unwrap().is_empty());
+  assert!(Vector::arange(0.0, 1.0, 0.0).unwrap().is_empty());
+  assert!(Vector::arange(0.0, 1.0, f64::NAN).unwrap().is_empty());
+  assert!(Vector::arange(f64::NAN, 1.0, 1.0).unwrap().is_empty());
+}
+
+#[test]
+fn arange_rejects_too_many_values() {
+  // The count does not fit in usize.
+  assert!(Vector::arange(0.0, 1e30, 1.0).is_err());
+  assert!(Vector::arange(0.0, f64::INFINITY, 1.0).is_err());
+  assert!(Vector::arange(0.0_f32, 1.0, 1e-30).is_err());
+  // The count fits, but the values cannot be allocated.
+  assert!(Vector::arange(0.0, 1e17, 1.0).is_err());
+}